@@ -1003,6 +1003,7 @@ impl ComponentSchema {
         let xml = features.extract_vec_xml_feature(type_tree)?;
         let max_items = pop_feature!(features => Feature::MaxItems(_));
         let min_items = pop_feature!(features => Feature::MinItems(_));
+        let unique_items = pop_feature!(features => Feature::UniqueItems(_));
         let nullable: Option<Nullable> =
             pop_feature!(features => Feature::Nullable(_)).into_inner();
         let default = pop_feature!(features => Feature::Default(_));
@@ -1045,7 +1046,9 @@ impl ComponentSchema {
 
         schema_references.extend(component_schema.schema_references);
 
-        let unique = match matches!(type_tree.generic_type, Some(GenericType::Set)) {
+        let unique = match matches!(type_tree.generic_type, Some(GenericType::Set))
+            && unique_items.is_none()
+        {
             true => quote! {
                 .unique_items(true)
             },
@@ -1088,6 +1091,11 @@ impl ComponentSchema {
             tokens.extend(min_items.to_token_stream())
         }
 
+        if let Some(unique_items) = unique_items {
+            validate(&unique_items);
+            tokens.extend(unique_items.to_token_stream())
+        }
+
         content_encoding.to_tokens(tokens)?;
         content_media_type.to_tokens(tokens)?;
         default.to_tokens(tokens)?;
@@ -77,6 +77,8 @@ pub enum Feature {
     Example(attributes::Example),
     Examples(attributes::Examples),
     Default(attributes::Default),
+    Const(attributes::Const),
+    EnumValues(attributes::EnumValues),
     Inline(attributes::Inline),
     XmlAttr(attributes::XmlAttr),
     Format(attributes::Format),
@@ -89,6 +91,10 @@ pub enum Feature {
     RenameAll(attributes::RenameAll),
     Style(attributes::Style),
     AllowReserved(attributes::AllowReserved),
+    AllowEmptyValue(attributes::AllowEmptyValue),
+    CookieSameSite(attributes::CookieSameSite),
+    ExampleUrl(attributes::ExampleUrl),
+    CookieHttpOnly(attributes::CookieHttpOnly),
     Explode(attributes::Explode),
     ParameterIn(attributes::ParameterIn),
     IntoParamsNames(attributes::IntoParamsNames),
@@ -100,6 +106,19 @@ pub enum Feature {
     Required(attributes::Required),
     ContentEncoding(attributes::ContentEncoding),
     ContentMediaType(attributes::ContentMediaType),
+    ContentType(attributes::ContentType),
+    Alias(attributes::Alias),
+    NoSchema(attributes::NoSchema),
+    NoDescription(attributes::NoDescription),
+    ParameterContent(attributes::ParameterContent),
+    ParameterEncoding(attributes::ParameterEncoding),
+    Extensions(attributes::Extensions),
+    ParameterExamples(attributes::ParameterExamples),
+    SchemaTypeOverride(attributes::SchemaTypeOverride),
+    SchemaRef(attributes::SchemaRef),
+    DeprecatedSince(attributes::DeprecatedSince),
+    DescriptionTemplate(attributes::DescriptionTemplate),
+    DescriptionRef(attributes::DescriptionRef),
     Discriminator(attributes::Discriminator),
     Bound(attributes::Bound),
     Ignore(attributes::Ignore),
@@ -114,6 +133,7 @@ pub enum Feature {
     Pattern(validation::Pattern),
     MaxItems(validation::MaxItems),
     MinItems(validation::MinItems),
+    UniqueItems(validation::UniqueItems),
     MaxProperties(validation::MaxProperties),
     MinProperties(validation::MinProperties),
 }
@@ -145,8 +165,9 @@ impl Feature {
             Feature::MinItems(min_items) => min_items.validate(
                 ValidatorChain::new(&AboveZeroUsize(&min_items.0)).next(&IsVec(type_tree)),
             ),
+            Feature::UniqueItems(unique_items) => unique_items.validate(IsVec(type_tree)),
             unsupported => {
-                const SUPPORTED_VARIANTS: [&str; 10] = [
+                const SUPPORTED_VARIANTS: [&str; 11] = [
                     "multiple_of",
                     "maximum",
                     "minimum",
@@ -157,6 +178,7 @@ impl Feature {
                     "pattern",
                     "max_items",
                     "min_items",
+                    "unique_items",
                 ];
                 panic!(
                     "Unsupported variant: `{unsupported}` for Validate::validate, expected one of: {variants}",
@@ -171,8 +193,11 @@ impl ToTokensDiagnostics for Feature {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) -> Result<(), Diagnostics> {
         let feature = match &self {
             Feature::Default(default) => quote! { .default(#default) },
+            Feature::Const(const_value) => quote! { .const_value(Some(#const_value)) },
             Feature::Example(example) => quote! { .example(Some(#example)) },
             Feature::Examples(examples) => quote! { .examples(#examples) },
+            Feature::EnumValues(enum_values) if enum_values.is_empty() => TokenStream::new(),
+            Feature::EnumValues(enum_values) => quote! { .enum_values(Some(#enum_values)) },
             Feature::XmlAttr(xml) => quote! { .xml(Some(#xml)) },
             Feature::Format(format) => quote! { .format(Some(#format)) },
             Feature::WriteOnly(write_only) => quote! { .write_only(Some(#write_only)) },
@@ -186,6 +211,9 @@ impl ToTokensDiagnostics for Feature {
             Feature::AllowReserved(allow_reserved) => {
                 quote! { .allow_reserved(Some(#allow_reserved)) }
             }
+            Feature::AllowEmptyValue(allow_empty_value) => {
+                quote! { .allow_empty_value(Some(#allow_empty_value)) }
+            }
             Feature::Explode(explode) => quote! { .explode(Some(#explode)) },
             Feature::Maximum(maximum) => quote! { .maximum(Some(#maximum)) },
             Feature::Minimum(minimum) => quote! { .minimum(Some(#minimum)) },
@@ -200,6 +228,7 @@ impl ToTokensDiagnostics for Feature {
             Feature::Pattern(pattern) => quote! { .pattern(Some(#pattern)) },
             Feature::MaxItems(max_items) => quote! { .max_items(Some(#max_items)) },
             Feature::MinItems(min_items) => quote! { .min_items(Some(#min_items)) },
+            Feature::UniqueItems(unique_items) => quote! { .unique_items(#unique_items) },
             Feature::MaxProperties(max_properties) => {
                 quote! { .max_properties(Some(#max_properties)) }
             }
@@ -214,6 +243,22 @@ impl ToTokensDiagnostics for Feature {
             }
             Feature::ContentEncoding(content_encoding) => quote! { .content_encoding(#content_encoding) },
             Feature::ContentMediaType(content_media_type) => quote! { .content_media_type(#content_media_type) },
+            Feature::ContentType(_) => return Err(Diagnostics::new("ContentType does not support `ToTokens`")),
+            Feature::Alias(_) => return Err(Diagnostics::new("Alias does not support `ToTokens`")),
+            Feature::NoSchema(_) => return Err(Diagnostics::new("NoSchema does not support `ToTokens`")),
+            Feature::NoDescription(_) => return Err(Diagnostics::new("NoDescription does not support `ToTokens`")),
+            Feature::CookieSameSite(_) => return Err(Diagnostics::new("CookieSameSite does not support `ToTokens`")),
+            Feature::ExampleUrl(_) => return Err(Diagnostics::new("ExampleUrl does not support `ToTokens`")),
+            Feature::CookieHttpOnly(_) => return Err(Diagnostics::new("CookieHttpOnly does not support `ToTokens`")),
+            Feature::ParameterContent(_) => return Err(Diagnostics::new("ParameterContent does not support `ToTokens`")),
+            Feature::ParameterEncoding(_) => return Err(Diagnostics::new("ParameterEncoding does not support `ToTokens`")),
+            Feature::Extensions(_) => return Err(Diagnostics::new("Extensions does not support `ToTokens`")),
+            Feature::ParameterExamples(examples) => examples.to_token_stream(),
+            Feature::SchemaTypeOverride(_) => return Err(Diagnostics::new("SchemaTypeOverride does not support `ToTokens`")),
+            Feature::SchemaRef(_) => return Err(Diagnostics::new("SchemaRef does not support `ToTokens`")),
+            Feature::DeprecatedSince(_) => return Err(Diagnostics::new("DeprecatedSince does not support `ToTokens`")),
+            Feature::DescriptionTemplate(_) => return Err(Diagnostics::new("DescriptionTemplate does not support `ToTokens`")),
+            Feature::DescriptionRef(_) => return Err(Diagnostics::new("DescriptionRef does not support `ToTokens`")),
             Feature::Discriminator(discriminator) => quote! { .discriminator(Some(#discriminator)) },
             Feature::Bound(_) => {
                 // specially handled on generating impl blocks.
@@ -265,8 +310,10 @@ impl Display for Feature {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Feature::Default(default) => default.fmt(f),
+            Feature::Const(const_value) => const_value.fmt(f),
             Feature::Example(example) => example.fmt(f),
             Feature::Examples(examples) => examples.fmt(f),
+            Feature::EnumValues(enum_values) => enum_values.fmt(f),
             Feature::XmlAttr(xml) => xml.fmt(f),
             Feature::Format(format) => format.fmt(f),
             Feature::WriteOnly(write_only) => write_only.fmt(f),
@@ -277,6 +324,10 @@ impl Display for Feature {
             Feature::Style(style) => style.fmt(f),
             Feature::ParameterIn(parameter_in) => parameter_in.fmt(f),
             Feature::AllowReserved(allow_reserved) => allow_reserved.fmt(f),
+            Feature::AllowEmptyValue(allow_empty_value) => allow_empty_value.fmt(f),
+            Feature::CookieSameSite(same_site) => same_site.fmt(f),
+            Feature::ExampleUrl(example_url) => example_url.fmt(f),
+            Feature::CookieHttpOnly(http_only) => http_only.fmt(f),
             Feature::Explode(explode) => explode.fmt(f),
             Feature::RenameAll(rename_all) => rename_all.fmt(f),
             Feature::ValueType(value_type) => value_type.fmt(f),
@@ -292,6 +343,7 @@ impl Display for Feature {
             Feature::Pattern(pattern) => pattern.fmt(f),
             Feature::MaxItems(max_items) => max_items.fmt(f),
             Feature::MinItems(min_items) => min_items.fmt(f),
+            Feature::UniqueItems(unique_items) => unique_items.fmt(f),
             Feature::MaxProperties(max_properties) => max_properties.fmt(f),
             Feature::MinProperties(min_properties) => min_properties.fmt(f),
             Feature::SchemaWith(schema_with) => schema_with.fmt(f),
@@ -302,6 +354,19 @@ impl Display for Feature {
             Feature::Required(required) => required.fmt(f),
             Feature::ContentEncoding(content_encoding) => content_encoding.fmt(f),
             Feature::ContentMediaType(content_media_type) => content_media_type.fmt(f),
+            Feature::ContentType(content_type) => content_type.fmt(f),
+            Feature::Alias(alias) => alias.fmt(f),
+            Feature::NoSchema(no_schema) => no_schema.fmt(f),
+            Feature::NoDescription(no_description) => no_description.fmt(f),
+            Feature::ParameterContent(content) => content.fmt(f),
+            Feature::ParameterEncoding(encoding) => encoding.fmt(f),
+            Feature::Extensions(extensions) => extensions.fmt(f),
+            Feature::ParameterExamples(examples) => examples.fmt(f),
+            Feature::SchemaTypeOverride(schema_type) => schema_type.fmt(f),
+            Feature::SchemaRef(schema_ref) => schema_ref.fmt(f),
+            Feature::DeprecatedSince(since) => since.fmt(f),
+            Feature::DescriptionTemplate(template) => template.fmt(f),
+            Feature::DescriptionRef(description_ref) => description_ref.fmt(f),
             Feature::Discriminator(discriminator) => discriminator.fmt(f),
             Feature::Bound(bound) => bound.fmt(f),
             Feature::Ignore(ignore) => ignore.fmt(f),
@@ -314,8 +379,10 @@ impl Validatable for Feature {
     fn is_validatable(&self) -> bool {
         match &self {
             Feature::Default(default) => default.is_validatable(),
+            Feature::Const(const_value) => const_value.is_validatable(),
             Feature::Example(example) => example.is_validatable(),
             Feature::Examples(examples) => examples.is_validatable(),
+            Feature::EnumValues(enum_values) => enum_values.is_validatable(),
             Feature::XmlAttr(xml) => xml.is_validatable(),
             Feature::Format(format) => format.is_validatable(),
             Feature::WriteOnly(write_only) => write_only.is_validatable(),
@@ -326,6 +393,10 @@ impl Validatable for Feature {
             Feature::Style(style) => style.is_validatable(),
             Feature::ParameterIn(parameter_in) => parameter_in.is_validatable(),
             Feature::AllowReserved(allow_reserved) => allow_reserved.is_validatable(),
+            Feature::AllowEmptyValue(allow_empty_value) => allow_empty_value.is_validatable(),
+            Feature::CookieSameSite(same_site) => same_site.is_validatable(),
+            Feature::ExampleUrl(example_url) => example_url.is_validatable(),
+            Feature::CookieHttpOnly(http_only) => http_only.is_validatable(),
             Feature::Explode(explode) => explode.is_validatable(),
             Feature::RenameAll(rename_all) => rename_all.is_validatable(),
             Feature::ValueType(value_type) => value_type.is_validatable(),
@@ -341,6 +412,7 @@ impl Validatable for Feature {
             Feature::Pattern(pattern) => pattern.is_validatable(),
             Feature::MaxItems(max_items) => max_items.is_validatable(),
             Feature::MinItems(min_items) => min_items.is_validatable(),
+            Feature::UniqueItems(unique_items) => unique_items.is_validatable(),
             Feature::MaxProperties(max_properties) => max_properties.is_validatable(),
             Feature::MinProperties(min_properties) => min_properties.is_validatable(),
             Feature::SchemaWith(schema_with) => schema_with.is_validatable(),
@@ -353,6 +425,19 @@ impl Validatable for Feature {
             Feature::Required(required) => required.is_validatable(),
             Feature::ContentEncoding(content_encoding) => content_encoding.is_validatable(),
             Feature::ContentMediaType(content_media_type) => content_media_type.is_validatable(),
+            Feature::ContentType(content_type) => content_type.is_validatable(),
+            Feature::Alias(alias) => alias.is_validatable(),
+            Feature::NoSchema(no_schema) => no_schema.is_validatable(),
+            Feature::NoDescription(no_description) => no_description.is_validatable(),
+            Feature::ParameterContent(content) => content.is_validatable(),
+            Feature::ParameterEncoding(encoding) => encoding.is_validatable(),
+            Feature::Extensions(extensions) => extensions.is_validatable(),
+            Feature::ParameterExamples(examples) => examples.is_validatable(),
+            Feature::SchemaTypeOverride(schema_type) => schema_type.is_validatable(),
+            Feature::SchemaRef(schema_ref) => schema_ref.is_validatable(),
+            Feature::DeprecatedSince(since) => since.is_validatable(),
+            Feature::DescriptionTemplate(template) => template.is_validatable(),
+            Feature::DescriptionRef(description_ref) => description_ref.is_validatable(),
             Feature::Discriminator(discriminator) => discriminator.is_validatable(),
             Feature::Bound(bound) => bound.is_validatable(),
             Feature::Ignore(ignore) => ignore.is_validatable(),
@@ -377,8 +462,10 @@ macro_rules! is_validatable {
 
 is_validatable! {
     attributes::Default,
+    attributes::Const,
     attributes::Example,
     attributes::Examples,
+    attributes::EnumValues,
     attributes::XmlAttr,
     attributes::Format,
     attributes::WriteOnly,
@@ -390,6 +477,10 @@ is_validatable! {
     attributes::Style,
     attributes::ParameterIn,
     attributes::AllowReserved,
+    attributes::AllowEmptyValue,
+    attributes::CookieSameSite,
+    attributes::ExampleUrl,
+    attributes::CookieHttpOnly,
     attributes::Explode,
     attributes::ValueType,
     attributes::Inline,
@@ -402,6 +493,19 @@ is_validatable! {
     attributes::Required,
     attributes::ContentEncoding,
     attributes::ContentMediaType,
+    attributes::ContentType,
+    attributes::Alias,
+    attributes::NoSchema,
+    attributes::NoDescription,
+    attributes::ParameterContent,
+    attributes::ParameterEncoding,
+    attributes::Extensions,
+    attributes::ParameterExamples,
+    attributes::SchemaTypeOverride,
+    attributes::SchemaRef,
+    attributes::DeprecatedSince,
+    attributes::DescriptionTemplate,
+    attributes::DescriptionRef,
     attributes::Discriminator,
     attributes::Bound,
     attributes::Ignore,
@@ -416,6 +520,7 @@ is_validatable! {
     validation::Pattern = true,
     validation::MaxItems = true,
     validation::MinItems = true,
+    validation::UniqueItems = true,
     validation::MaxProperties,
     validation::MinProperties
 }
@@ -431,6 +536,8 @@ macro_rules! parse_features {
                 while !input.is_empty() {
                     let ident = input.parse::<syn::Ident>().or_else(|_| {
                         input.parse::<syn::Token![as]>().map(|as_| syn::Ident::new("as", as_.span))
+                    }).or_else(|_| {
+                        input.parse::<syn::Token![const]>().map(|const_| syn::Ident::new("const", const_.span))
                     }).map_err(|error| {
                         syn::Error::new(
                             error.span(),
@@ -610,7 +717,9 @@ macro_rules! impl_feature_into_inner {
 impl_feature_into_inner! {
     attributes::Example,
     attributes::Examples,
+    attributes::EnumValues,
     attributes::Default,
+    attributes::Const,
     attributes::Inline,
     attributes::XmlAttr,
     attributes::Format,
@@ -623,6 +732,10 @@ impl_feature_into_inner! {
     attributes::RenameAll,
     attributes::Style,
     attributes::AllowReserved,
+    attributes::AllowEmptyValue,
+    attributes::CookieSameSite,
+    attributes::ExampleUrl,
+    attributes::CookieHttpOnly,
     attributes::Explode,
     attributes::ParameterIn,
     attributes::IntoParamsNames,
@@ -632,6 +745,18 @@ impl_feature_into_inner! {
     attributes::As,
     attributes::Required,
     attributes::AdditionalProperties,
+    attributes::ContentType,
+    attributes::NoSchema,
+    attributes::NoDescription,
+    attributes::ParameterContent,
+    attributes::ParameterEncoding,
+    attributes::Extensions,
+    attributes::ParameterExamples,
+    attributes::SchemaTypeOverride,
+    attributes::SchemaRef,
+    attributes::DeprecatedSince,
+    attributes::DescriptionTemplate,
+    attributes::DescriptionRef,
     attributes::Discriminator,
     attributes::Bound,
     attributes::Ignore,
@@ -646,6 +771,7 @@ impl_feature_into_inner! {
     validation::Pattern,
     validation::MaxItems,
     validation::MinItems,
+    validation::UniqueItems,
     validation::MaxProperties,
     validation::MinProperties,
 }
@@ -1,17 +1,19 @@
 use std::mem;
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
 use syn::parse::ParseStream;
 use syn::punctuated::Punctuated;
 use syn::token::Paren;
-use syn::{Error, LitStr, Token, TypePath, WherePredicate};
+use syn::{Error, Lit, LitStr, Token, TypePath, WherePredicate};
 
 use crate::component::serde::RenameRule;
 use crate::component::{schema, GenericType, TypeTree};
 use crate::parse_utils::{LitBoolOrExprPath, LitStrOrExpr};
+use crate::path::example::Example as ParameterExample;
+use crate::path::media_type::ParsedType;
 use crate::path::parameter::{self, ParameterStyle};
-use crate::schema_type::KnownFormat;
+use crate::schema_type::{KnownFormat, SchemaType, SchemaTypeInner};
 use crate::{parse_utils, AnyValue, Array, Diagnostics};
 
 use super::{impl_feature, Feature, Parse};
@@ -20,21 +22,30 @@ use quote::quote;
 impl_feature! {
     #[derive(Clone)]
     #[cfg_attr(feature = "debug", derive(Debug))]
-    pub struct Default(pub(crate) Option<AnyValue>);
+    pub struct Default(pub(crate) Option<AnyValue>, pub(crate) Span);
 }
 
 impl Default {
     pub fn new_default_trait(struct_ident: Ident, field_ident: syn::Member) -> Self {
-        Self(Some(AnyValue::new_default_trait(struct_ident, field_ident)))
+        Self(
+            Some(AnyValue::new_default_trait(struct_ident, field_ident)),
+            Span::call_site(),
+        )
+    }
+
+    /// Span of the `default` attribute.
+    pub fn span(&self) -> Span {
+        self.1
     }
 }
 
 impl Parse for Default {
-    fn parse(input: syn::parse::ParseStream, _: proc_macro2::Ident) -> syn::Result<Self> {
+    fn parse(input: syn::parse::ParseStream, attribute: proc_macro2::Ident) -> syn::Result<Self> {
         if input.peek(syn::Token![=]) {
-            parse_utils::parse_next(input, || AnyValue::parse_any(input)).map(|any| Self(Some(any)))
+            parse_utils::parse_next(input, || AnyValue::parse_any(input))
+                .map(|any| Self(Some(any), attribute.span()))
         } else {
-            Ok(Self(None))
+            Ok(Self(None, attribute.span()))
         }
     }
 }
@@ -54,6 +65,31 @@ impl From<self::Default> for Feature {
     }
 }
 
+impl_feature! {"const" =>
+    /// Single allowed value of the schema parsed from `const = ...` syntax.
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Const(AnyValue);
+}
+
+impl Parse for Const {
+    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_next(input, || AnyValue::parse_any(input)).map(Self)
+    }
+}
+
+impl ToTokens for Const {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(self.0.to_token_stream())
+    }
+}
+
+impl From<Const> for Feature {
+    fn from(value: Const) -> Self {
+        Feature::Const(value)
+    }
+}
+
 impl_feature! {
     #[derive(Clone)]
     #[cfg_attr(feature = "debug", derive(Debug))]
@@ -118,6 +154,111 @@ impl From<Examples> for Feature {
     }
 }
 
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct EnumValues(Vec<EnumValue>);
+}
+
+impl EnumValues {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Validate that kind of every literal value is compatible with the given primitive
+    /// [`SchemaType`], e.g. string values are only allowed for `string` types. Error is reported
+    /// at the first incompatible value.
+    pub fn validate_type(&self, schema_type: &SchemaType) -> Result<(), Diagnostics> {
+        let incompatible = self.0.iter().find_map(|value| {
+            let (is_valid, error) = match &value.lit {
+                Lit::Str(_) => (
+                    schema_type.is_string(),
+                    "string value can only be used with `string` type",
+                ),
+                Lit::Int(_) => (
+                    schema_type.is_number(),
+                    "integer value can only be used with `integer` or `number` type",
+                ),
+                Lit::Float(_) => (
+                    schema_type.is_number() && !schema_type.is_integer(),
+                    "float value can only be used with `number` type",
+                ),
+                Lit::Bool(_) => (
+                    schema_type.is_boolean(),
+                    "boolean value can only be used with `boolean` type",
+                ),
+                _ => return None,
+            };
+
+            (!is_valid).then(|| (value.lit.span(), error))
+        });
+
+        match incompatible {
+            Some((span, error)) => Err(Diagnostics::with_span(
+                span,
+                format!("`enum_values` error: {error}"),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Parse for EnumValues {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_next(input, || {
+            let values;
+            syn::bracketed!(values in input);
+
+            Punctuated::<EnumValue, Token![,]>::parse_terminated(&values)
+                .map(|values| Self(values.into_iter().collect()))
+        })
+    }
+}
+
+impl ToTokens for EnumValues {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        Array::Borrowed(&self.0).to_tokens(tokens)
+    }
+}
+
+impl From<EnumValues> for Feature {
+    fn from(value: EnumValues) -> Self {
+        Feature::EnumValues(value)
+    }
+}
+
+/// Single literal value of [`EnumValues`] e.g. _`"asc"`_, _`1`_ or _`-1.5`_.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct EnumValue {
+    minus: Option<Token![-]>,
+    lit: Lit,
+}
+
+impl syn::parse::Parse for EnumValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let minus = input.parse::<Option<Token![-]>>()?;
+        let lit = input.parse::<Lit>().map_err(|error| {
+            Error::new(
+                error.span(),
+                "unexpected token, expected literal value e.g. \"value\", 1 or true",
+            )
+        })?;
+
+        Ok(Self { minus, lit })
+    }
+}
+
+impl ToTokens for EnumValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { minus, lit } = self;
+        tokens.extend(quote! { serde_json::json!(#minus #lit) })
+    }
+}
+
 impl_feature! {"xml" =>
     #[derive(Default, Clone)]
     #[cfg_attr(feature = "debug", derive(Debug))]
@@ -132,6 +273,14 @@ impl XmlAttr {
         type_tree: &TypeTree,
     ) -> Result<(Option<XmlAttr>, Option<XmlAttr>), Diagnostics> {
         if matches!(type_tree.generic_type, Some(GenericType::Vec)) {
+            if let Some(attribute_ident) = self.0.is_attribute.as_ref() {
+                return Err(Diagnostics::with_span(
+                    attribute_ident.span(),
+                    "cannot use `attribute` attribute in slice field type",
+                )
+                .help("Xml attributes can only hold single values, try removing `attribute` attribute or make your field non `Vec`"));
+            }
+
             let mut value_xml = mem::take(self);
             let vec_xml = schema::xml::XmlAttr::with_wrapped(
                 mem::take(&mut value_xml.0.is_wrapped),
@@ -183,12 +332,24 @@ impl From<XmlAttr> for Feature {
 impl_feature! {
     #[derive(Clone)]
     #[cfg_attr(feature = "debug", derive(Debug))]
-    pub struct Format(KnownFormat);
+    pub struct Format(KnownFormat, Span);
+}
+
+impl Format {
+    pub fn known_format(&self) -> &KnownFormat {
+        &self.0
+    }
+
+    /// Span of the `format` attribute.
+    pub fn span(&self) -> Span {
+        self.1
+    }
 }
 
 impl Parse for Format {
-    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        parse_utils::parse_next(input, || input.parse::<KnownFormat>()).map(Self)
+    fn parse(input: syn::parse::ParseStream, attribute: Ident) -> syn::Result<Self> {
+        parse_utils::parse_next(input, || input.parse::<KnownFormat>())
+            .map(|format| Self(format, attribute.span()))
     }
 }
 
@@ -207,12 +368,22 @@ impl From<Format> for Feature {
 impl_feature! {
     #[derive(Clone, Copy)]
     #[cfg_attr(feature = "debug", derive(Debug))]
-    pub struct WriteOnly(bool);
+    pub struct WriteOnly(bool, Span);
+}
+
+impl WriteOnly {
+    pub fn is_true(&self) -> bool {
+        self.0
+    }
+
+    pub fn span(&self) -> Span {
+        self.1
+    }
 }
 
 impl Parse for WriteOnly {
-    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        parse_utils::parse_bool_or_true(input).map(Self)
+    fn parse(input: syn::parse::ParseStream, attribute: Ident) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(|value| Self(value, attribute.span()))
     }
 }
 
@@ -231,12 +402,22 @@ impl From<WriteOnly> for Feature {
 impl_feature! {
     #[derive(Clone, Copy)]
     #[cfg_attr(feature = "debug", derive(Debug))]
-    pub struct ReadOnly(bool);
+    pub struct ReadOnly(bool, Span);
+}
+
+impl ReadOnly {
+    pub fn is_true(&self) -> bool {
+        self.0
+    }
+
+    pub fn span(&self) -> Span {
+        self.1
+    }
 }
 
 impl Parse for ReadOnly {
-    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        parse_utils::parse_bool_or_true(input).map(Self)
+    fn parse(input: syn::parse::ParseStream, attribute: Ident) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(|value| Self(value, attribute.span()))
     }
 }
 
@@ -328,6 +509,10 @@ impl Rename {
     pub fn into_value(self) -> String {
         self.0
     }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 impl Parse for Rename {
@@ -362,7 +547,7 @@ impl RenameAll {
 
 impl Parse for RenameAll {
     fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        let litstr = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+        let litstr = parse_utils::parse_next(input, || parse_utils::parse_literal_str(input))?;
 
         litstr
             .value()
@@ -381,18 +566,34 @@ impl From<RenameAll> for Feature {
 impl_feature! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[derive(Clone)]
-    pub struct Style(ParameterStyle);
+    pub struct Style(ParameterStyle, Span);
+}
+
+impl Style {
+    pub fn get_style(&self) -> &ParameterStyle {
+        &self.0
+    }
+
+    /// Span of the style value.
+    pub fn span(&self) -> Span {
+        self.1
+    }
 }
 
 impl From<ParameterStyle> for Style {
     fn from(style: ParameterStyle) -> Self {
-        Self(style)
+        Self(style, Span::call_site())
     }
 }
 
 impl Parse for Style {
     fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        parse_utils::parse_next(input, || input.parse::<ParameterStyle>().map(Self))
+        parse_utils::parse_next(input, || {
+            let span = input.span();
+            input
+                .parse::<ParameterStyle>()
+                .map(|style| Self(style, span))
+        })
     }
 }
 
@@ -435,12 +636,18 @@ impl From<ParameterIn> for Feature {
 impl_feature! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[derive(Clone)]
-    pub struct AllowReserved(bool);
+    pub struct AllowReserved(bool, Ident);
+}
+
+impl AllowReserved {
+    pub fn get_attribute(&self) -> &Ident {
+        &self.1
+    }
 }
 
 impl Parse for AllowReserved {
-    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        parse_utils::parse_bool_or_true(input).map(Self)
+    fn parse(input: syn::parse::ParseStream, attribute: Ident) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(|allow_reserved| Self(allow_reserved, attribute))
     }
 }
 
@@ -456,6 +663,158 @@ impl From<AllowReserved> for Feature {
     }
 }
 
+impl_feature! {
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct AllowEmptyValue(bool, Ident);
+}
+
+impl AllowEmptyValue {
+    pub fn get_attribute(&self) -> &Ident {
+        &self.1
+    }
+}
+
+impl Parse for AllowEmptyValue {
+    fn parse(input: syn::parse::ParseStream, attribute: Ident) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input)
+            .map(|allow_empty_value| Self(allow_empty_value, attribute))
+    }
+}
+
+impl ToTokens for AllowEmptyValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<AllowEmptyValue> for Feature {
+    fn from(value: AllowEmptyValue) -> Self {
+        Feature::AllowEmptyValue(value)
+    }
+}
+
+impl_feature! {
+    /// Fully qualified example URL of a parameter parsed from
+    /// `example_url = "https://api.example.com/items/1"` syntax.
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct ExampleUrl(LitStr);
+}
+
+impl ExampleUrl {
+    /// Check whether the URL starts with a scheme e.g. `https:` followed by non-empty content.
+    fn has_scheme(url: &str) -> bool {
+        let Some((scheme, rest)) = url.split_once(':') else {
+            return false;
+        };
+
+        scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+            && !rest.is_empty()
+            && !url.contains(char::is_whitespace)
+    }
+}
+
+impl Parse for ExampleUrl {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        let url = parse_utils::parse_next(input, || parse_utils::parse_literal_str(input))?;
+        if !Self::has_scheme(&url.value()) {
+            return Err(Error::new(
+                url.span(),
+                r#"expected URL with scheme e.g. `example_url = "https://api.example.com/items/1"`"#,
+            ));
+        }
+
+        Ok(Self(url))
+    }
+}
+
+impl ToTokens for ExampleUrl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<ExampleUrl> for Feature {
+    fn from(value: ExampleUrl) -> Self {
+        Feature::ExampleUrl(value)
+    }
+}
+
+impl_feature! {"x_samesite" =>
+    /// `SameSite` attribute of a cookie parameter parsed from `x_samesite = "Strict"` syntax.
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct CookieSameSite(LitStr, Ident);
+}
+
+impl CookieSameSite {
+    pub fn get_attribute(&self) -> &Ident {
+        &self.1
+    }
+}
+
+impl Parse for CookieSameSite {
+    fn parse(input: ParseStream, attribute: Ident) -> syn::Result<Self> {
+        let same_site = parse_utils::parse_next(input, || parse_utils::parse_literal_str(input))?;
+        if !matches!(&*same_site.value(), "Strict" | "Lax" | "None") {
+            return Err(Error::new(
+                same_site.span(),
+                "unexpected `x_samesite`, expected one of: \"Strict\", \"Lax\", \"None\"",
+            ));
+        }
+
+        Ok(Self(same_site, attribute))
+    }
+}
+
+impl ToTokens for CookieSameSite {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<CookieSameSite> for Feature {
+    fn from(value: CookieSameSite) -> Self {
+        Feature::CookieSameSite(value)
+    }
+}
+
+impl_feature! {"x_http_only" =>
+    /// `HttpOnly` attribute of a cookie parameter parsed from `x_http_only` or
+    /// `x_http_only = bool` syntax.
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct CookieHttpOnly(bool, Ident);
+}
+
+impl CookieHttpOnly {
+    pub fn get_attribute(&self) -> &Ident {
+        &self.1
+    }
+}
+
+impl Parse for CookieHttpOnly {
+    fn parse(input: ParseStream, attribute: Ident) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(|http_only| Self(http_only, attribute))
+    }
+}
+
+impl ToTokens for CookieHttpOnly {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<CookieHttpOnly> for Feature {
+    fn from(value: CookieHttpOnly) -> Self {
+        Feature::CookieHttpOnly(value)
+    }
+}
+
 impl_feature! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[derive(Clone)]
@@ -474,6 +833,12 @@ impl ToTokens for Explode {
     }
 }
 
+impl From<bool> for Explode {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
 impl From<Explode> for Feature {
     fn from(value: Explode) -> Self {
         Feature::Explode(value)
@@ -624,9 +989,23 @@ impl_feature! {
     ///
     /// This feature supports only syntax parsed from utoipa specific macro attributes, it does not
     /// support Rust `#[deprecated]` attribute.
+    ///
+    /// Optional reason can be given with `deprecated = "reason"` syntax which will mark the item
+    /// as deprecated.
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[derive(Clone)]
-    pub struct Deprecated(bool);
+    pub struct Deprecated(bool, Option<LitStr>);
+}
+
+impl Deprecated {
+    pub fn is_true(&self) -> bool {
+        self.0
+    }
+
+    /// Get the deprecation reason if one was defined with `deprecated = "reason"` syntax.
+    pub fn reason(&self) -> Option<&LitStr> {
+        self.1.as_ref()
+    }
 }
 
 impl Parse for Deprecated {
@@ -634,7 +1013,13 @@ impl Parse for Deprecated {
     where
         Self: std::marker::Sized,
     {
-        parse_utils::parse_bool_or_true(input).map(Self)
+        if input.peek(Token![=]) && input.peek2(LitStr) {
+            input.parse::<Token![=]>()?;
+
+            Ok(Self(true, Some(input.parse::<LitStr>()?)))
+        } else {
+            parse_utils::parse_bool_or_true(input).map(|deprecated| Self(deprecated, None))
+        }
     }
 }
 
@@ -653,7 +1038,7 @@ impl From<Deprecated> for Feature {
 
 impl From<bool> for Deprecated {
     fn from(value: bool) -> Self {
-        Self(value)
+        Self(value, None)
     }
 }
 
@@ -829,6 +1214,707 @@ impl From<ContentMediaType> for Feature {
     }
 }
 
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct ContentType(String);
+}
+
+impl ContentType {
+    /// Check whether the value is syntactically plausible media type e.g. `application/json`.
+    fn is_media_type(value: &str) -> bool {
+        let essence = value.split(';').next().unwrap_or_default().trim();
+
+        !essence.contains(char::is_whitespace)
+            && matches!(essence.split_once('/'), Some((ty, subtype))
+                if !ty.is_empty() && !subtype.is_empty() && !subtype.contains('/'))
+    }
+}
+
+impl Parse for ContentType {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        let content_type =
+            parse_utils::parse_next(input, || parse_utils::parse_literal_str(input))?;
+
+        if Self::is_media_type(&content_type.value()) {
+            Ok(Self(content_type.value()))
+        } else {
+            Err(Error::new(
+                content_type.span(),
+                "unexpected content_type, expected media type e.g. `application/json`",
+            ))
+        }
+    }
+}
+
+impl ToTokens for ContentType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<ContentType> for Feature {
+    fn from(value: ContentType) -> Self {
+        Self::ContentType(value)
+    }
+}
+
+impl_feature! {"content" =>
+    /// Media type of a parameter with its schema type parsed from
+    /// `content = { "application/json": Type }` syntax.
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct ParameterContent(String, ParsedType<'static>, Span);
+}
+
+impl ParameterContent {
+    pub fn media_type(&self) -> (&str, &ParsedType<'static>) {
+        (self.0.as_str(), &self.1)
+    }
+
+    /// Span of the `content` attribute.
+    pub fn span(&self) -> Span {
+        self.2
+    }
+
+    fn parse_media_type(input: ParseStream) -> syn::Result<(LitStr, ParsedType<'static>)> {
+        let media_type = input.parse::<LitStr>()?;
+        if !ContentType::is_media_type(&media_type.value()) {
+            return Err(Error::new(
+                media_type.span(),
+                "unexpected content media type, expected media type e.g. `application/json`",
+            ));
+        }
+        input.parse::<Token![:]>()?;
+
+        Ok((media_type, input.parse()?))
+    }
+}
+
+impl Parse for ParameterContent {
+    fn parse(input: ParseStream, attribute: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        input.parse::<Token![=]>()?;
+        let content;
+        syn::braced!(content in input);
+        let mut media_types =
+            Punctuated::<_, Token![,]>::parse_terminated_with(&content, Self::parse_media_type)?
+                .into_iter();
+
+        let Some((media_type, parsed_type)) = media_types.next() else {
+            return Err(Error::new(
+                attribute.span(),
+                "expected one media type in `content` e.g. `content = { \"application/json\": Type }`",
+            ));
+        };
+        if let Some((extra_media_type, _)) = media_types.next() {
+            return Err(Error::new(
+                extra_media_type.span(),
+                "`content` of a parameter can only have one media type",
+            ));
+        }
+
+        Ok(Self(media_type.value(), parsed_type, attribute.span()))
+    }
+}
+
+impl From<ParameterContent> for Feature {
+    fn from(value: ParameterContent) -> Self {
+        Self::ParameterContent(value)
+    }
+}
+
+impl_feature! {"encoding" =>
+    /// Encodings of the properties of a parameter with `content_type` or `content` parsed from
+    /// `encoding = { "property": { style = Form, explode = true } }` syntax.
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct ParameterEncoding(Vec<(String, PropertyEncoding)>, Span);
+}
+
+impl ParameterEncoding {
+    /// Span of the `encoding` attribute.
+    pub fn span(&self) -> Span {
+        self.1
+    }
+
+    fn parse_property(input: ParseStream) -> syn::Result<(LitStr, PropertyEncoding)> {
+        let property = input.parse::<LitStr>()?;
+        input.parse::<Token![:]>()?;
+
+        Ok((property, input.parse()?))
+    }
+}
+
+impl Parse for ParameterEncoding {
+    fn parse(input: ParseStream, attribute: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        input.parse::<Token![=]>()?;
+        let content;
+        syn::braced!(content in input);
+        let properties =
+            Punctuated::<_, Token![,]>::parse_terminated_with(&content, Self::parse_property)?;
+
+        if properties.is_empty() {
+            return Err(Error::new(
+                attribute.span(),
+                "expected at least one property in `encoding` e.g. `encoding = { \"tags\": { style = Form } }`",
+            ));
+        }
+
+        let mut encoding = Vec::<(String, PropertyEncoding)>::with_capacity(properties.len());
+        for (property, property_encoding) in properties {
+            let value = property.value();
+            if encoding.iter().any(|(existing, _)| *existing == value) {
+                return Err(Error::new(
+                    property.span(),
+                    format!("duplicate property `{value}` in `encoding`"),
+                ));
+            }
+            encoding.push((value, property_encoding));
+        }
+
+        Ok(Self(encoding, attribute.span()))
+    }
+}
+
+impl ToTokens for ParameterEncoding {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for (property, encoding) in &self.0 {
+            tokens.extend(quote! { .encoding(#property, #encoding) })
+        }
+    }
+}
+
+impl From<ParameterEncoding> for Feature {
+    fn from(value: ParameterEncoding) -> Self {
+        Self::ParameterEncoding(value)
+    }
+}
+
+/// Encoding of a single property within [`ParameterEncoding`] parsed from
+/// `{ content_type = "...", style = Form, explode = true, allow_reserved = false }` syntax.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct PropertyEncoding {
+    content_type: Option<String>,
+    style: Option<ParameterStyle>,
+    explode: Option<bool>,
+    allow_reserved: Option<bool>,
+}
+
+impl syn::parse::Parse for PropertyEncoding {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE: &str =
+            "unexpected attribute, expected any of: content_type, style, explode, allow_reserved";
+        let mut encoding = PropertyEncoding::default();
+        let content;
+        syn::braced!(content in input);
+
+        while !content.is_empty() {
+            let attribute = content
+                .parse::<Ident>()
+                .map_err(|error| Error::new(error.span(), EXPECTED_ATTRIBUTE))?;
+            match &*attribute.to_string() {
+                "content_type" => {
+                    encoding.content_type =
+                        Some(<ContentType as Parse>::parse(&content, attribute)?.0)
+                }
+                "style" => {
+                    encoding.style = Some(parse_utils::parse_next(&content, || {
+                        content.parse::<ParameterStyle>()
+                    })?)
+                }
+                "explode" => encoding.explode = Some(parse_utils::parse_bool_or_true(&content)?),
+                "allow_reserved" => {
+                    encoding.allow_reserved = Some(parse_utils::parse_bool_or_true(&content)?)
+                }
+                _ => return Err(Error::new(attribute.span(), EXPECTED_ATTRIBUTE)),
+            }
+
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(encoding)
+    }
+}
+
+impl ToTokens for PropertyEncoding {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(quote! { utoipa::openapi::encoding::EncodingBuilder::new() });
+        if let Some(content_type) = &self.content_type {
+            tokens.extend(quote! { .content_type(Some(#content_type)) });
+        }
+        if let Some(style) = &self.style {
+            tokens.extend(quote! { .style(Some(#style)) });
+        }
+        if let Some(explode) = self.explode {
+            tokens.extend(quote! { .explode(Some(#explode)) });
+        }
+        if let Some(allow_reserved) = self.allow_reserved {
+            tokens.extend(quote! { .allow_reserved(Some(#allow_reserved)) });
+        }
+        tokens.extend(quote! { .build() });
+    }
+}
+
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Alias(String);
+}
+
+impl Alias {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Parse for Alias {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_next_literal_str(input).map(Self)
+    }
+}
+
+impl ToTokens for Alias {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<Alias> for Feature {
+    fn from(value: Alias) -> Self {
+        Self::Alias(value)
+    }
+}
+
+impl_feature! {"extensions" =>
+    /// OpenAPI vendor extensions of a parameter parsed from
+    /// `extensions("x-name" = "value", "x-other" = 1)` syntax.
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Extensions(Vec<(LitStr, Lit)>);
+}
+
+impl Extensions {
+    fn parse_extension(input: ParseStream) -> syn::Result<(LitStr, Lit)> {
+        let name = input.parse::<LitStr>()?;
+        if !name.value().starts_with("x-") {
+            return Err(Error::new(
+                name.span(),
+                format!("extension name `{}` must start with `x-`", name.value()),
+            ));
+        }
+
+        parse_utils::parse_next(input, || {
+            let unexpected_value = || {
+                parse_utils::unexpected_value_error(
+                    input,
+                    "unexpected extension value, expected literal string, number or boolean",
+                )
+            };
+            if !input.peek(Lit) {
+                return Err(unexpected_value());
+            }
+            let error = unexpected_value();
+            match input.parse::<Lit>()? {
+                value @ (Lit::Str(_) | Lit::Int(_) | Lit::Float(_) | Lit::Bool(_)) => {
+                    Ok((name, value))
+                }
+                _ => Err(error),
+            }
+        })
+    }
+}
+
+impl Parse for Extensions {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        let content;
+        syn::parenthesized!(content in input);
+        Punctuated::<(LitStr, Lit), Token![,]>::parse_terminated_with(
+            &content,
+            Self::parse_extension,
+        )
+        .map(|extensions| Self(extensions.into_iter().collect()))
+    }
+}
+
+impl ToTokens for Extensions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for (name, value) in &self.0 {
+            tokens.extend(quote! { .add(#name, #value) })
+        }
+    }
+}
+
+impl From<Extensions> for Feature {
+    fn from(value: Extensions) -> Self {
+        Self::Extensions(value)
+    }
+}
+
+impl_feature! {"examples" =>
+    /// Named OpenAPI examples of a parameter parsed from
+    /// `examples(("name" = (summary = "...", value = ...)), ...)` syntax.
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct ParameterExamples(Vec<ParameterExample>, Span);
+}
+
+impl ParameterExamples {
+    /// Span of the `examples` attribute.
+    pub fn span(&self) -> Span {
+        self.1
+    }
+}
+
+impl Parse for ParameterExamples {
+    fn parse(input: ParseStream, attribute: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_comma_separated_within_parenthesis::<ParameterExample>(input)
+            .map(|examples| Self(examples.into_iter().collect(), attribute.span()))
+    }
+}
+
+impl ToTokens for ParameterExamples {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let examples = self
+            .0
+            .iter()
+            .map(|example| {
+                let name = &example.name;
+                quote!( (#name, #example) )
+            })
+            .collect::<Array<TokenStream>>();
+
+        tokens.extend(quote! { .examples_from_iter(#examples) })
+    }
+}
+
+impl From<ParameterExamples> for Feature {
+    fn from(value: ParameterExamples) -> Self {
+        Self::ParameterExamples(value)
+    }
+}
+
+impl_feature! {"schema_ref" =>
+    /// Name of the component schema used as `$ref` schema of a parameter parsed from
+    /// `schema_ref = "Name"` syntax.
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct SchemaRef(LitStr);
+}
+
+impl SchemaRef {
+    /// Span of the referenced schema name.
+    pub fn span(&self) -> Span {
+        self.0.span()
+    }
+}
+
+impl Parse for SchemaRef {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        let name = parse_utils::parse_next(input, || parse_utils::parse_literal_str(input))?;
+        if name.value().trim().is_empty() {
+            return Err(Error::new(
+                name.span(),
+                "expected schema name e.g. `schema_ref = \"Status\"`",
+            ));
+        }
+
+        Ok(Self(name))
+    }
+}
+
+impl ToTokens for SchemaRef {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.0;
+        tokens.extend(quote! { utoipa::openapi::Ref::from_schema_name(#name) })
+    }
+}
+
+impl From<SchemaRef> for Feature {
+    fn from(value: SchemaRef) -> Self {
+        Self::SchemaRef(value)
+    }
+}
+
+impl_feature! {"since" =>
+    /// Version since which a deprecated parameter has been deprecated parsed from
+    /// `since = "2.3.0"` syntax.
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct DeprecatedSince(LitStr);
+}
+
+impl DeprecatedSince {
+    /// Span of the version string.
+    pub fn span(&self) -> Span {
+        self.0.span()
+    }
+}
+
+impl Parse for DeprecatedSince {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        let since = parse_utils::parse_next(input, || parse_utils::parse_literal_str(input))?;
+        if since.value().trim().is_empty() {
+            return Err(Error::new(
+                since.span(),
+                "expected version e.g. `since = \"2.3.0\"`",
+            ));
+        }
+
+        Ok(Self(since))
+    }
+}
+
+impl ToTokens for DeprecatedSince {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<DeprecatedSince> for Feature {
+    fn from(value: DeprecatedSince) -> Self {
+        Self::DeprecatedSince(value)
+    }
+}
+
+impl_feature! {
+    /// Description of a parameter with `{name}` placeholder parsed from
+    /// `description_template = "The {name} of the item"` syntax.
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct DescriptionTemplate(LitStr);
+}
+
+impl DescriptionTemplate {
+    /// Span of the template string.
+    pub fn span(&self) -> Span {
+        self.0.span()
+    }
+
+    /// Expand the template to [`Description`] by substituting `{name}` placeholders with the
+    /// given name. Other braces are kept as is.
+    pub fn expand(&self, name: &str) -> Description {
+        Description::from(self.0.value().replace("{name}", name))
+    }
+}
+
+impl Parse for DescriptionTemplate {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_next(input, || parse_utils::parse_literal_str(input)).map(Self)
+    }
+}
+
+impl ToTokens for DescriptionTemplate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<DescriptionTemplate> for Feature {
+    fn from(value: DescriptionTemplate) -> Self {
+        Self::DescriptionTemplate(value)
+    }
+}
+
+impl_feature! {
+    /// Name of the parameter of the same `params(...)` to take the description from parsed from
+    /// `description_ref = "page"` syntax.
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct DescriptionRef(LitStr);
+}
+
+impl DescriptionRef {
+    /// Span of the referenced parameter name.
+    pub fn span(&self) -> Span {
+        self.0.span()
+    }
+
+    /// Name of the referenced parameter.
+    pub fn name(&self) -> String {
+        self.0.value()
+    }
+}
+
+impl Parse for DescriptionRef {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_next(input, || parse_utils::parse_literal_str(input)).map(Self)
+    }
+}
+
+impl ToTokens for DescriptionRef {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<DescriptionRef> for Feature {
+    fn from(value: DescriptionRef) -> Self {
+        Self::DescriptionRef(value)
+    }
+}
+
+impl_feature! {"schema_type" =>
+    /// Override of the schema `type` of a parameter parsed from `schema_type = "string"` syntax.
+    #[derive(Clone, Copy)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct SchemaTypeOverride(SchemaTypeInner, Span);
+}
+
+impl SchemaTypeOverride {
+    pub fn schema_type(&self) -> SchemaTypeInner {
+        self.0
+    }
+
+    /// Span of the `schema_type` value.
+    pub fn span(&self) -> Span {
+        self.1
+    }
+}
+
+impl Parse for SchemaTypeOverride {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        const EXPECTED_SCHEMA_TYPE: &str =
+            "unexpected schema_type, expected one of: string, integer, number, boolean, object";
+        let schema_type = parse_utils::parse_next(input, || parse_utils::parse_literal_str(input))
+            .map_err(|error| Error::new(error.span(), EXPECTED_SCHEMA_TYPE))?;
+
+        let schema_type_inner = match &*schema_type.value() {
+            "string" => SchemaTypeInner::String,
+            "integer" => SchemaTypeInner::Integer,
+            "number" => SchemaTypeInner::Number,
+            "boolean" => SchemaTypeInner::Boolean,
+            "object" => SchemaTypeInner::Object,
+            _ => return Err(Error::new(schema_type.span(), EXPECTED_SCHEMA_TYPE)),
+        };
+
+        Ok(Self(schema_type_inner, schema_type.span()))
+    }
+}
+
+impl From<SchemaTypeOverride> for Feature {
+    fn from(value: SchemaTypeOverride) -> Self {
+        Self::SchemaTypeOverride(value)
+    }
+}
+
+impl_feature! {
+    /// Shorthand for `schema_type = "boolean"` of boolean flag parameters.
+    #[derive(Clone, Copy)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Boolean(Span);
+}
+
+impl Parse for Boolean {
+    fn parse(_: ParseStream, attribute: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        Ok(Self(attribute.span()))
+    }
+}
+
+impl From<Boolean> for Feature {
+    fn from(value: Boolean) -> Self {
+        Self::SchemaTypeOverride(SchemaTypeOverride(SchemaTypeInner::Boolean, value.0))
+    }
+}
+
+impl_feature! {
+    /// Opt-out from the default `string` schema of parameters without a type.
+    #[derive(Clone, Copy)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct NoSchema(bool);
+}
+
+impl NoSchema {
+    pub fn is_true(&self) -> bool {
+        self.0
+    }
+}
+
+impl Parse for NoSchema {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_bool_or_true(input).map(Self)
+    }
+}
+
+impl From<NoSchema> for Feature {
+    fn from(value: NoSchema) -> Self {
+        Self::NoSchema(value)
+    }
+}
+
+impl_feature! {
+    /// Opt-out from the description resolved from the doc comments of a field.
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct NoDescription(bool, Ident);
+}
+
+impl NoDescription {
+    pub fn is_true(&self) -> bool {
+        self.0
+    }
+
+    pub fn get_attribute(&self) -> &Ident {
+        &self.1
+    }
+}
+
+impl Parse for NoDescription {
+    fn parse(input: ParseStream, attribute: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_bool_or_true(input).map(|value| Self(value, attribute))
+    }
+}
+
+impl From<NoDescription> for Feature {
+    fn from(value: NoDescription) -> Self {
+        Self::NoDescription(value)
+    }
+}
+
 // discriminator = ...
 // discriminator(property_name = ..., mapping(
 //      (value = ...),
@@ -963,7 +2049,7 @@ impl_feature! {
 
 impl Parse for Bound {
     fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        let litstr = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+        let litstr = parse_utils::parse_next(input, || parse_utils::parse_literal_str(input))?;
         let bounds =
             syn::parse::Parser::parse_str(<Punctuated<_, _>>::parse_terminated, &litstr.value())
                 .map_err(|err| syn::Error::new(litstr.span(), err.to_string()))?;
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use proc_macro2::{Ident, Literal, Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
 use syn::parse::ParseStream;
-use syn::LitStr;
+use syn::{LitFloat, LitInt, Token};
 
 use crate::{parse_utils, Diagnostics};
 
@@ -79,7 +79,20 @@ impl ToTokens for NumberValue {
 #[inline]
 fn parse_next_number_value(input: ParseStream) -> syn::Result<NumberValue> {
     use syn::parse::Parse;
-    parse_utils::parse_next(input, || NumberValue::parse(input))
+    parse_utils::parse_next(input, || {
+        let fork = input.fork();
+        if fork.peek(Token![-]) {
+            fork.parse::<Token![-]>()?;
+        }
+        if fork.peek(LitInt) || fork.peek(LitFloat) {
+            NumberValue::parse(input)
+        } else {
+            Err(parse_utils::unexpected_value_error(
+                &fork,
+                "expected integer or float literal",
+            ))
+        }
+    })
 }
 
 impl_feature! {
@@ -374,7 +387,7 @@ impl Parse for Pattern {
     where
         Self: Sized,
     {
-        parse_utils::parse_next(input, || input.parse::<LitStr>())
+        parse_utils::parse_next(input, || parse_utils::parse_literal_str(input))
             .map(|pattern| Self(pattern.value(), ident))
     }
 }
@@ -465,6 +478,43 @@ impl From<MinItems> for Feature {
     }
 }
 
+impl_feature! {
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct UniqueItems(bool, Ident);
+}
+
+impl Validate for UniqueItems {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`unique_items` error: {}", error))
+                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-uniqueitems")),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for UniqueItems {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_utils::parse_bool_or_true(input).map(|unique_items| Self(unique_items, ident))
+    }
+}
+
+impl ToTokens for UniqueItems {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<UniqueItems> for Feature {
+    fn from(value: UniqueItems) -> Self {
+        Feature::UniqueItems(value)
+    }
+}
+
 impl_feature! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[derive(Clone)]
@@ -1,5 +1,5 @@
 use crate::component::{GenericType, TypeTree};
-use crate::schema_type::SchemaType;
+use crate::schema_type::{KnownFormat, SchemaType};
 
 use super::validation::NumberValue;
 
@@ -43,6 +43,18 @@ impl Validator for IsInteger<'_> {
     }
 }
 
+pub struct IsNotDateFormat<'a>(pub &'a KnownFormat);
+
+impl Validator for IsNotDateFormat<'_> {
+    fn is_valid(&self) -> Result<(), &'static str> {
+        if self.0.is_date_format() {
+            Err("cannot be used with `date` or `date-time` format, use `pattern` to restrict the value instead")
+        } else {
+            Ok(())
+        }
+    }
+}
+
 pub struct IsVec<'a>(pub(super) &'a TypeTree<'a>);
 
 impl Validator for IsVec<'_> {
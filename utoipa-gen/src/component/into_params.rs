@@ -13,20 +13,20 @@ use crate::{
         features::{
             self,
             attributes::{
-                AdditionalProperties, AllowReserved, Example, Explode, Format, Ignore, Inline,
-                IntoParamsNames, Nullable, ReadOnly, Rename, RenameAll, SchemaWith, Style,
-                WriteOnly, XmlAttr,
+                AdditionalProperties, AllowReserved, Description, EnumValues, Example, Explode,
+                Format, Ignore, Inline, IntoParamsNames, NoDescription, Nullable, ReadOnly, Rename,
+                RenameAll, SchemaWith, Style, WriteOnly, XmlAttr,
             },
             validation::{
                 ExclusiveMaximum, ExclusiveMinimum, MaxItems, MaxLength, Maximum, MinItems,
-                MinLength, Minimum, MultipleOf, Pattern,
+                MinLength, Minimum, MultipleOf, Pattern, UniqueItems,
             },
         },
         FieldRename,
     },
     doc_comment::CommentAttributes,
     parse_utils::LitBoolOrExprPath,
-    Array, Diagnostics, OptionExt, Required, ToTokensDiagnostics,
+    Array, AttributesExt, Diagnostics, OptionExt, Required, ToTokensDiagnostics,
 };
 
 use super::{
@@ -123,7 +123,10 @@ impl ToTokensDiagnostics for IntoParams {
             .collect::<Result<Vec<_>, Diagnostics>>()?
             .into_iter()
             .filter_map(|(index, field, field_serde_params, field_features)| {
-                if field_serde_params.skip {
+                // fields hidden from rustdoc with `#[doc(hidden)]` are not documented either
+                if field_serde_params.skip
+                    || CommentAttributes::from_attributes(&field.attrs).is_hidden()
+                {
                     None
                 } else {
                     Some((index, field, field_serde_params, field_features))
@@ -282,6 +285,8 @@ impl Parse for FieldFeatures {
             Explode,
             SchemaWith,
             component::features::attributes::Required,
+            Description,
+            NoDescription,
             // param schema features
             Inline,
             Format,
@@ -300,6 +305,8 @@ impl Parse for FieldFeatures {
             Pattern,
             MaxItems,
             MinItems,
+            UniqueItems,
+            EnumValues,
             AdditionalProperties,
             Ignore
         )))
@@ -372,16 +379,52 @@ impl Param {
             tokens.extend(quote! { .deprecated(Some(#deprecated)) });
         }
 
+        let mut extensions = TokenStream::new();
+        // OpenAPI does not have a field for deprecation note, thus it is added as extension
+        if let Some(note) = field.attrs.deprecated_note() {
+            extensions.extend(quote! { .add("x-deprecated-reason", #note) });
+        }
+
         let schema_with = pop_feature!(param_features => Feature::SchemaWith(_));
         if let Some(schema_with) = schema_with {
             let schema_with = crate::as_tokens_or_diagnostics!(&schema_with);
+            tokens.extend(Param::extensions_tokens(extensions));
             tokens.extend(quote! { .schema(Some(#schema_with)).build() });
         } else {
-            let description =
-                CommentAttributes::from_attributes(&field.attrs).as_formatted_string();
-            if !description.is_empty() {
-                tokens.extend(quote! { .description(Some(#description))})
+            let description = pop_feature!(param_features => Feature::Description(_));
+            let no_description: Option<NoDescription> =
+                pop_feature!(param_features => Feature::NoDescription(_)).into_inner();
+            // explicitly defined description will override the field doc comment
+            if let Some(description) = description {
+                if let Some(no_description) = no_description.filter(NoDescription::is_true) {
+                    return Err(Diagnostics::with_span(
+                        no_description.get_attribute().span(),
+                        "`no_description` cannot be used together with `description`",
+                    ));
+                }
+                tokens.extend(description.try_to_token_stream()?);
+            } else if !no_description.is_some_and(|no_description| no_description.is_true()) {
+                let comments = CommentAttributes::from_attributes(&field.attrs);
+                let description = comments.as_formatted_string();
+                // whitespace only doc comments e.g. empty `///` would result empty description
+                if !description.trim().is_empty() {
+                    tokens.extend(quote! { .description(Some(#description))})
+                }
+
+                #[cfg(feature = "localized_docs")]
+                {
+                    let (locales, texts): (Vec<&str>, Vec<String>) = comments
+                        .locales()
+                        .filter_map(|locale| Some((locale, comments.localized(locale)?)))
+                        .unzip();
+                    if !locales.is_empty() {
+                        extensions.extend(quote! {
+                            .add("x-translations", serde_json::json!({ #( #locales: #texts ),* }))
+                        });
+                    }
+                }
             }
+            tokens.extend(Param::extensions_tokens(extensions));
 
             let value_type = pop_feature!(param_features => Feature::ValueType(_) as Option<features::attributes::ValueType>);
             let component = value_type
@@ -443,6 +486,22 @@ impl Param {
         Ok(Self { tokens })
     }
 
+    /// Get tokens of the parameter extensions for the `.add(...)` calls of `extensions` or
+    /// nothing if there are no extensions.
+    fn extensions_tokens(extensions: TokenStream) -> TokenStream {
+        if extensions.is_empty() {
+            return TokenStream::new();
+        }
+
+        quote! {
+            .extensions(Some(
+                utoipa::openapi::extensions::ExtensionsBuilder::new()
+                    #extensions
+                    .build()
+            ))
+        }
+    }
+
     /// Resolve [`Param`] features and split features into two [`Vec`]s. Features are split by
     /// whether they should be rendered in [`Param`] itself or in [`Param`]s schema.
     ///
@@ -481,6 +540,8 @@ impl Param {
                     | Feature::Pattern(_)
                     | Feature::MaxItems(_)
                     | Feature::MinItems(_)
+                    | Feature::UniqueItems(_)
+                    | Feature::EnumValues(_)
                     | Feature::AdditionalProperties(_) => {
                         schema_features.push(feature);
                     }
@@ -351,10 +351,12 @@ impl NamedStructSchema {
                 }
 
                 let field_rules = serde::parse_value(&field.attrs);
-                let field_rules = match field_rules {
+                let mut field_rules = match field_rules {
                     Ok(field_rules) => field_rules,
                     Err(diagnostics) => return Some(Err(diagnostics)),
                 };
+                // fields hidden from rustdoc with `#[doc(hidden)]` are not documented either
+                field_rules.skip |= CommentAttributes::from_attributes(&field.attrs).is_hidden();
                 let field_options = Self::get_named_struct_field_options(
                     root,
                     field,
@@ -731,7 +733,7 @@ impl UnnamedStructSchema {
 
                 features.extend(inline);
 
-                if pop_feature!(features => Feature::Default(crate::features::attributes::Default(None)))
+                if pop_feature!(features => Feature::Default(crate::features::attributes::Default(None, _)))
                     .is_some()
                 {
                     let index: syn::Index = 0.into();
@@ -10,7 +10,7 @@ pub struct XmlAttr {
     pub name: Option<String>,
     pub namespace: Option<String>,
     pub prefix: Option<String>,
-    pub is_attribute: bool,
+    pub is_attribute: Option<Ident>,
     pub is_wrapped: Option<Ident>,
     pub wrap_name: Option<String>,
 }
@@ -50,7 +50,11 @@ impl Parse for XmlAttr {
                     xml.prefix =
                         Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?.value())
                 }
-                "attribute" => xml.is_attribute = parse_utils::parse_bool_or_true(input)?,
+                "attribute" => {
+                    if parse_utils::parse_bool_or_true(input)? {
+                        xml.is_attribute = Some(attribute);
+                    }
+                }
                 "wrapped" => {
                     // wrapped or wrapped(name = "wrap_name")
                     if input.peek(Paren) {
@@ -110,7 +114,7 @@ impl ToTokens for XmlAttr {
             })
         }
 
-        if self.is_attribute {
+        if self.is_attribute.is_some() {
             tokens.extend(quote! {
                 .attribute(Some(true))
             })
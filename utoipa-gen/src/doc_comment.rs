@@ -1,46 +1,245 @@
-use std::path::PathBuf;
-use syn::{Attribute, Expr, Lit, Meta, MetaNameValue};
+use quote::ToTokens;
+use std::cell::RefCell;
+#[cfg(feature = "localized_docs")]
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Expr, ExprLit, ExprMacro,
+    Lit, LitStr, Meta, MetaNameValue,
+};
 
 const DOC_ATTRIBUTE_TYPE: &str = "doc";
+#[cfg(feature = "localized_docs")]
+const LOCALIZED_DOC_ATTRIBUTE_TYPE: &str = "utoipa_doc";
+
+thread_local! {
+    /// Content of files read with `include_str!(...)` keyed by the absolute path of the file
+    /// along with the modification time of the file when it was read. Same file is typically
+    /// included by many items thus it is read only once per process unless it has been modified.
+    static INCLUDED_FILES: RefCell<HashMap<PathBuf, (SystemTime, String)>> =
+        RefCell::new(HashMap::new());
+}
 
 /// CommentAttributes holds Vec of parsed doc comments
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub(crate) struct CommentAttributes(pub(crate) Vec<String>);
+pub(crate) struct CommentAttributes {
+    pub(crate) docs: Vec<String>,
+    /// Whether `#[doc(hidden)]` was found among the attributes
+    hidden: bool,
+    /// Lines of `#[utoipa_doc(locale = "..", text = "...")]` attributes by the locale.
+    #[cfg(feature = "localized_docs")]
+    localized: BTreeMap<String, Vec<String>>,
+}
 
 impl CommentAttributes {
     /// Creates new [`CommentAttributes`] instance from [`Attribute`] slice filtering out all
     /// other attributes which are not `doc` comments
     pub(crate) fn from_attributes(attributes: &[Attribute]) -> Self {
-        let mut docs = attributes
+        let mut hidden = false;
+        let sources = attributes
             .iter()
-            .filter_map(|attr| {
-                if !matches!(attr.path().get_ident(), Some(ident) if ident == DOC_ATTRIBUTE_TYPE) {
-                    return None;
+            .flat_map(|attr| Self::doc_metas(&attr.meta))
+            .filter_map(|meta| match meta {
+                Meta::NameValue(name_value) => {
+                    let is_include = matches!(name_value.value, Expr::Macro(_));
+                    Self::extract_doc_value(&name_value).map(|doc| (doc, is_include))
                 }
-
-                // ignore `#[doc(hidden)]` and similar tags.
-                if let Meta::NameValue(name_value) = &attr.meta {
-                    return Self::extract_doc_value(name_value);
+                // ignore `#[doc(alias = "...")]` and similar tags but keep track of `#[doc(hidden)]`.
+                Meta::List(list) => {
+                    hidden |= list
+                        .parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+                        .is_ok_and(|metas| metas.iter().any(|meta| meta.path().is_ident("hidden")));
+                    None
                 }
-                None
-            })
-            .collect::<Vec<_>>();
+                Meta::Path(_) => None,
+            });
+
+        // Consecutive `///` comments form a single block while every `include_str!(...)` is a
+        // source of its own. Distinct sources are separated with a blank line so that they do not
+        // run together e.g. the last line of comments and a heading of the included file.
+        // Lines are paired with whether they are lines of a block comment.
+        let mut lines = Vec::<(String, bool)>::new();
+        let mut previous_is_include = false;
+        for (doc, is_include) in sources {
+            let is_boundary = is_include || previous_is_include;
+            let needs_separator = lines
+                .last()
+                .is_some_and(|(previous, _)| !previous.is_empty() && !previous.ends_with('\n'))
+                && !doc.is_empty()
+                && !doc.starts_with('\n');
+            if is_boundary && needs_separator {
+                lines.push((String::new(), false));
+            }
+
+            if is_include || !doc.contains('\n') {
+                lines.push((doc, false));
+            } else {
+                // block comments `/** */` arrive as a single multi-line literal. The body is
+                // indented relative to the comment markers instead of the `///` comments thus
+                // the common indentation of the body is stripped on its own.
+                let mut block = doc.split('\n').map(String::from).collect::<Vec<_>>();
+                Self::strip_common_indent(&mut block);
+                lines.extend(block.into_iter().map(|line| (line, true)));
+            }
+            previous_is_include = is_include;
+        }
 
-        // Calculate the minimum indentation of all non-empty lines and strip them.
-        let min_indent = docs
+        Self::strip_common_indent(
+            lines
+                .iter_mut()
+                .filter(|(_, is_block)| !is_block)
+                .map(|(line, _)| line),
+        );
+        let mut docs = lines.into_iter().map(|(line, _)| line).collect::<Vec<_>>();
+
+        // docs consisting only of blank lines e.g. empty `///` comments are considered absent
+        // in order not to produce whitespace only descriptions
+        if docs.iter().all(|line| line.trim().is_empty()) {
+            docs.clear();
+        }
+
+        Self {
+            docs,
+            hidden,
+            #[cfg(feature = "localized_docs")]
+            localized: Self::localized_docs(attributes),
+        }
+    }
+
+    /// Strip the minimum indentation of all non-blank `lines` from every line. Both spaces and
+    /// tabs count as a single indentation unit.
+    fn strip_common_indent<'l>(lines: impl IntoIterator<Item = &'l mut String>) {
+        let mut lines = lines.into_iter().collect::<Vec<_>>();
+        let indent = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+        let min_indent = lines
             .iter()
-            .filter(|s| !s.is_empty())
-            .map(|s| s.len() - s.trim_start_matches(' ').len())
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| indent(line))
             .min()
             .unwrap_or(0);
 
-        for line in &mut docs {
-            if !line.is_empty() {
-                line.drain(..min_indent);
+        for line in &mut lines {
+            let line_indent = indent(line);
+            line.drain(..min_indent.min(line_indent));
+        }
+    }
+
+    /// Collect `#[utoipa_doc(locale = "..", text = "...")]` attributes to lines by the locale.
+    /// Every attribute is a single line of the localized docs in the order of the attributes.
+    /// Malformed attributes are ignored the same way as unsupported `doc` attributes.
+    #[cfg(feature = "localized_docs")]
+    fn localized_docs(attributes: &[Attribute]) -> BTreeMap<String, Vec<String>> {
+        let mut localized = BTreeMap::<String, Vec<String>>::new();
+
+        for attribute in attributes
+            .iter()
+            .filter(|attribute| attribute.path().is_ident(LOCALIZED_DOC_ATTRIBUTE_TYPE))
+        {
+            let Ok(metas) =
+                attribute.parse_args_with(Punctuated::<MetaNameValue, Comma>::parse_terminated)
+            else {
+                continue;
+            };
+            let value_of = |name: &str| {
+                metas.iter().find_map(|meta| match &meta.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(value),
+                        ..
+                    }) if meta.path.is_ident(name) => Some(value.value()),
+                    _ => None,
+                })
+            };
+
+            if let (Some(locale), Some(text)) = (value_of("locale"), value_of("text")) {
+                localized.entry(locale).or_default().push(text);
+            }
+        }
+
+        localized
+    }
+
+    /// Returns localized docs of the given `locale` collected from
+    /// `#[utoipa_doc(locale = "..", text = "...")]` attributes joined with `\n` *(new line)*.
+    #[cfg(feature = "localized_docs")]
+    pub(crate) fn localized(&self, locale: &str) -> Option<String> {
+        self.localized.get(locale).map(|lines| lines.join("\n"))
+    }
+
+    /// Returns locales of the localized docs in alphabetical order.
+    #[cfg(feature = "localized_docs")]
+    pub(crate) fn locales(&self) -> impl Iterator<Item = &str> {
+        self.localized.keys().map(String::as_str)
+    }
+
+    /// Returns `doc` metas of the given attribute meta. Besides plain `#[doc ...]` attributes
+    /// `doc` metas are extracted from `#[cfg_attr(predicate, doc ...)]` attributes when the
+    /// predicate is unconditionally true e.g. `all()`.
+    ///
+    /// Proc macros cannot evaluate predicates such as `feature = "..."` thus `cfg_attr` attributes
+    /// depending on them are ignored.
+    fn doc_metas(meta: &Meta) -> Vec<Meta> {
+        if meta.path().is_ident(DOC_ATTRIBUTE_TYPE) {
+            return vec![meta.clone()];
+        }
+
+        let Meta::List(list) = meta else {
+            return Vec::new();
+        };
+        if !list.path.is_ident("cfg_attr") {
+            return Vec::new();
+        }
+
+        let Ok(metas) = list.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated) else {
+            return Vec::new();
+        };
+        let mut metas = metas.into_iter();
+
+        match metas.next() {
+            Some(predicate) if Self::evaluate_cfg_predicate(&predicate) == Some(true) => {
+                metas.flat_map(|meta| Self::doc_metas(&meta)).collect()
             }
+            _ => Vec::new(),
         }
+    }
 
-        Self(docs)
+    /// Evaluates `cfg` predicate composed of `all`, `any` and `not`. Returns `None` if the
+    /// predicate result cannot be known at macro expansion time.
+    fn evaluate_cfg_predicate(predicate: &Meta) -> Option<bool> {
+        let Meta::List(list) = predicate else {
+            return None;
+        };
+        let predicates = list
+            .parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+            .ok()?
+            .iter()
+            .map(Self::evaluate_cfg_predicate)
+            .collect::<Vec<_>>();
+
+        if list.path.is_ident("all") {
+            if predicates.contains(&Some(false)) {
+                Some(false)
+            } else {
+                predicates
+                    .iter()
+                    .all(|result| *result == Some(true))
+                    .then_some(true)
+            }
+        } else if list.path.is_ident("any") {
+            if predicates.contains(&Some(true)) {
+                Some(true)
+            } else {
+                predicates
+                    .iter()
+                    .all(|result| *result == Some(false))
+                    .then_some(false)
+            }
+        } else if list.path.is_ident("not") && predicates.len() == 1 {
+            predicates[0].map(|result| !result)
+        } else {
+            None
+        }
     }
 
     /// Extract documentation value from a name-value pair, handling both string literals
@@ -50,9 +249,9 @@ impl CommentAttributes {
             // Handle direct string literals
             Expr::Lit(doc_comment) => {
                 if let Lit::Str(doc) = &doc_comment.lit {
-                    let mut doc = doc.value();
-                    doc.truncate(doc.trim_end().len());
-                    Some(doc)
+                    Some(Self::trim_trailing_whitespace(
+                        &Self::normalize_line_endings(&doc.value()),
+                    ))
                 } else {
                     None
                 }
@@ -60,9 +259,10 @@ impl CommentAttributes {
             // Handle macro calls (like include_str!)
             Expr::Macro(macro_expr) => {
                 if macro_expr.mac.path.is_ident("include_str") {
-                    Some(Self::evaluate_include_str(
-                        &macro_expr.mac.tokens.to_string(),
-                    ))
+                    Self::evaluate_include_str_macro(macro_expr)
+                        .map(Self::trim_trailing_newline)
+                        .map(Some)
+                        .unwrap_or_else(|error| panic!("{error}"))
                 } else {
                     None
                 }
@@ -71,59 +271,176 @@ impl CommentAttributes {
         }
     }
 
-    /// Evaluates include_str! macro at compile time
-    fn evaluate_include_str(path_str: &str) -> String {
-        // Clean up the path string - remove quotes and whitespace
-        let path_str = path_str.trim().trim_matches('"');
+    /// Replaces Windows `\r\n` line endings with `\n` and strips any other trailing `\r` of the
+    /// lines so that docs authored on any platform render the same.
+    fn normalize_line_endings(doc: &str) -> String {
+        doc.split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Trims trailing whitespace of every line within the doc literal. Markdown line break of
+    /// exactly two trailing spaces is kept on lines other than the last one.
+    fn trim_trailing_whitespace(doc: &str) -> String {
+        doc.trim_end()
+            .split('\n')
+            .map(|line| {
+                let trimmed = line.trim_end();
+                let is_line_break = line.len() - trimmed.len() == 2 && line.ends_with("  ");
+                if is_line_break {
+                    line
+                } else {
+                    trimmed
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 
-        // Check if the path contains CARGO_MANIFEST_DIR
-        if path_str.contains("CARGO_MANIFEST_DIR") {
-            return Self::evaluate_manifest_dir_path(path_str);
+    /// Trims a single trailing new line of the included file content. Files typically end with
+    /// a new line which would otherwise show up as a stray blank line once the docs are joined.
+    /// Any additional trailing blank lines are considered intentional and are kept.
+    fn trim_trailing_newline(mut content: String) -> String {
+        if content.ends_with('\n') {
+            content.pop();
         }
+        content
+    }
 
-        // Handle direct paths
-        std::fs::read_to_string(path_str).unwrap_or_else(|err| {
-            panic!("Failed to read include_str! file: {}", err);
-        })
+    /// Evaluates `include_str!` macro call at compile time returning the content of the included
+    /// file.
+    ///
+    /// Errors are reported at the span of the macro call.
+    fn evaluate_include_str_macro(macro_expr: &ExprMacro) -> syn::Result<String> {
+        Self::evaluate_include_str(&macro_expr.mac.tokens.to_string())
+            .map_err(|error| syn::Error::new(macro_expr.span(), error))
     }
 
-    /// Evaluates paths that use CARGO_MANIFEST_DIR
-    fn evaluate_manifest_dir_path(path_str: &str) -> String {
-        // Get CARGO_MANIFEST_DIR from environment
-        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
-            .unwrap_or_else(|_| panic!("CARGO_MANIFEST_DIR not found in environment"));
+    /// Evaluates include_str! macro at compile time by reading the file of the path resolved
+    /// with [`CommentAttributes::resolve_include_path`].
+    fn evaluate_include_str(path_str: &str) -> Result<String, String> {
+        let path = Self::resolve_include_path(path_str)?;
 
-        // Extract the path after CARGO_MANIFEST_DIR
-        let processed_path = path_str
-            .replace("concat!", "")
-            .replace("env!(\"CARGO_MANIFEST_DIR\")", "")
-            .replace("env!('CARGO_MANIFEST_DIR')", "")
-            .replace(',', "");
+        Self::read_included_file(&path)
+            .map(|content| Self::normalize_line_endings(&content))
+            .map_err(|err| {
+                format!(
+                    "Failed to read include_str! file: {}: {}",
+                    path.display(),
+                    err
+                )
+            })
+    }
+
+    /// Resolve path of include_str! macro from the `path_str` tokens without accessing the file.
+    ///
+    /// Relative paths are resolved against current working directory.
+    fn resolve_include_path(path_str: &str) -> Result<PathBuf, String> {
+        let path_expr = syn::parse_str::<Expr>(path_str)
+            .map_err(|err| format!("Invalid include_str! path: {}", err))?;
 
-        let relative_path = processed_path
-            .trim()
-            .trim_matches(|c| c == '(' || c == ')')
-            .trim()
-            .trim_matches('"');
+        Self::evaluate_path_expr(&path_expr).map(PathBuf::from)
+    }
 
-        // Combine paths
-        let full_path = PathBuf::from(manifest_dir).join(relative_path.trim_start_matches('/'));
+    /// Read content of the included file using the [`INCLUDED_FILES`] cache. Files are compared
+    /// by their modification time in order not to return stale content in long running processes
+    /// e.g. IDE proc macro servers. Files without modification time support are never cached.
+    fn read_included_file(path: &Path) -> std::io::Result<String> {
+        let path = std::fs::canonicalize(path)?;
+        let modified = std::fs::metadata(&path).and_then(|metadata| metadata.modified());
 
-        let path_str = full_path
-            .to_str()
-            .unwrap_or_else(|| panic!("Invalid path: {:?}", full_path));
+        if let Ok(modified) = modified {
+            let cached = INCLUDED_FILES.with(|files| {
+                files
+                    .borrow()
+                    .get(&path)
+                    .filter(|(cached_modified, _)| *cached_modified == modified)
+                    .map(|(_, content)| content.clone())
+            });
+            if let Some(content) = cached {
+                return Ok(content);
+            }
+        }
 
-        std::fs::read_to_string(path_str)
-            .unwrap_or_else(|err| panic!("Failed to read include_str! file: {}", err))
+        let content = std::fs::read_to_string(&path)?;
+        if let Ok(modified) = modified {
+            INCLUDED_FILES
+                .with(|files| files.borrow_mut().insert(path, (modified, content.clone())));
+        }
+
+        Ok(content)
+    }
+
+    /// Evaluates include_str! path expression which may consist of string literals, `env!` and
+    /// `concat!` macro calls in any combination.
+    fn evaluate_path_expr(expr: &Expr) -> Result<String, String> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(path),
+                ..
+            }) => Ok(path.value()),
+            Expr::Macro(macro_expr) if macro_expr.mac.path.is_ident("concat") => macro_expr
+                .mac
+                .parse_body_with(Punctuated::<Expr, Comma>::parse_terminated)
+                .map_err(|err| format!("Invalid concat! in include_str! path: {}", err))?
+                .iter()
+                .map(Self::evaluate_path_expr)
+                .collect(),
+            Expr::Macro(macro_expr) if macro_expr.mac.path.is_ident("env") => {
+                // `env!` may have optional error message as second argument
+                let args = macro_expr
+                    .mac
+                    .parse_body_with(Punctuated::<LitStr, Comma>::parse_terminated)
+                    .ok()
+                    .filter(|args| !args.is_empty() && args.len() <= 2)
+                    .ok_or_else(|| "Invalid env! in include_str! path".to_string())?;
+                let name = args[0].value();
+
+                std::env::var(&name).map_err(|_| match args.get(1) {
+                    Some(message) => message.value(),
+                    None => format!(
+                        "environment variable `{}` not defined at compile time, used in include_str! path",
+                        name
+                    ),
+                })
+            }
+            _ => Err(format!(
+                "Unsupported include_str! path expression: {}",
+                expr.to_token_stream()
+            )),
+        }
+    }
+
+    /// Returns found `doc comments` split to _`summary`_ and _`description`_ following the rustdoc
+    /// convention. First non-empty paragraph is used as the _`summary`_ while the remaining
+    /// paragraphs are joined with `\n\n` to form the _`description`_.
+    pub(crate) fn as_summary_and_description(&self) -> (Option<String>, String) {
+        let mut paragraphs = self
+            .docs
+            .split(|line| line.trim().is_empty())
+            .filter(|paragraph| !paragraph.is_empty())
+            .map(|paragraph| paragraph.join("\n"));
+
+        let summary = paragraphs.next();
+        let description = paragraphs.collect::<Vec<_>>().join("\n\n");
+
+        (summary, description)
+    }
+
+    /// Returns `true` if `#[doc(hidden)]` was found among the attributes. This allows telling
+    /// hidden docs apart from absent docs.
+    pub(crate) fn is_hidden(&self) -> bool {
+        self.hidden
     }
 
     pub(crate) fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.docs.is_empty()
     }
 
     /// Returns found `doc comments` as formatted `String` joining them all with `\n` *(new line)*.
     pub(crate) fn as_formatted_string(&self) -> String {
-        self.0.join("\n")
+        self.docs.join("\n")
     }
 }
 
@@ -142,6 +459,138 @@ mod tests {
         assert_eq!(comments.as_formatted_string(), "Basic doc comment");
     }
 
+    #[test]
+    fn test_trailing_whitespace_trimmed_per_line() {
+        let attr: Attribute = parse_quote!(#[doc = "line1   \nline2\t\nbreak  \nline3  "]);
+        let comments = CommentAttributes::from_attributes(&[attr]);
+        assert_eq!(
+            comments.as_formatted_string(),
+            "line1\nline2\nbreak  \nline3"
+        );
+    }
+
+    #[test]
+    fn test_block_doc_comment_indentation() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc = " Summary of the item"]),
+            parse_quote!(#[doc = "\n     Block comment body\n       indented line\n\n     Last line\n "]),
+            parse_quote!(#[doc = " Trailing line comment"]),
+        ];
+        let comments = CommentAttributes::from_attributes(&attrs);
+
+        assert_eq!(
+            comments.docs,
+            [
+                "Summary of the item",
+                "",
+                "Block comment body",
+                "  indented line",
+                "",
+                "Last line",
+                "Trailing line comment"
+            ]
+        );
+
+        let attrs: Vec<Attribute> =
+            vec![parse_quote!(#[doc = "\n    First line\n      indented line\n    Last line\n"])];
+        let comments = CommentAttributes::from_attributes(&attrs);
+
+        assert_eq!(
+            comments.as_formatted_string(),
+            "\nFirst line\n  indented line\nLast line"
+        );
+    }
+
+    #[test]
+    fn test_tab_indented_doc_comment() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc = "\tFirst line"]),
+            parse_quote!(#[doc = "\t\tIndented line"]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = "\tLast line"]),
+        ];
+        let comments = CommentAttributes::from_attributes(&attrs);
+        assert_eq!(
+            comments.docs,
+            ["First line", "\tIndented line", "", "Last line"]
+        );
+    }
+
+    #[test]
+    fn test_doc_hidden() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc(alias = "other")]),
+            parse_quote!(#[doc = "Hidden doc comment"]),
+        ];
+        let comments = CommentAttributes::from_attributes(&attrs);
+        assert!(!comments.is_hidden());
+        assert_eq!(comments.as_formatted_string(), "Hidden doc comment");
+
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc(hidden)]),
+            parse_quote!(#[doc = "Hidden doc comment"]),
+        ];
+        let comments = CommentAttributes::from_attributes(&attrs);
+        assert!(comments.is_hidden());
+        assert!(!CommentAttributes::from_attributes(&[]).is_hidden());
+    }
+
+    #[test]
+    fn test_cfg_attr_doc_comment() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc = "Always"]),
+            parse_quote!(#[cfg_attr(all(), doc = "All")]),
+            parse_quote!(#[cfg_attr(not(any()), doc = "Not any", doc = "Second")]),
+            parse_quote!(#[cfg_attr(all(), cfg_attr(any(all(), feature = "x"), doc = "Nested"))]),
+            parse_quote!(#[cfg_attr(any(), doc = "Never")]),
+            parse_quote!(#[cfg_attr(feature = "extra", doc = "Unknown")]),
+            parse_quote!(#[cfg_attr(all(), derive(Debug))]),
+        ];
+        let comments = CommentAttributes::from_attributes(&attrs);
+
+        assert_eq!(
+            comments.as_formatted_string(),
+            "Always\nAll\nNot any\nSecond\nNested"
+        );
+    }
+
+    #[test]
+    fn test_summary_and_description() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " This is summary"]),
+            parse_quote!(#[doc = " spanning two lines"]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " First paragraph."]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " Second paragraph."]),
+        ];
+        let comments = CommentAttributes::from_attributes(&attrs);
+
+        let (summary, description) = comments.as_summary_and_description();
+        assert_eq!(
+            summary.as_deref(),
+            Some("This is summary\nspanning two lines")
+        );
+        assert_eq!(description, "First paragraph.\n\nSecond paragraph.");
+    }
+
+    #[test]
+    fn test_blank_doc_lines_are_empty() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = "   "]),
+            parse_quote!(#[doc = ""]),
+        ];
+
+        let comments = CommentAttributes::from_attributes(&attrs);
+
+        assert!(comments.is_empty());
+        assert_eq!(comments.as_formatted_string(), "");
+        assert_eq!(comments.as_summary_and_description(), (None, String::new()));
+    }
+
     #[test]
     fn test_manifest_dir_path() {
         // Create a temporary directory
@@ -164,9 +613,237 @@ mod tests {
             test_file_path.file_name().unwrap().to_str().unwrap()
         );
 
-        let result = CommentAttributes::evaluate_include_str(&path_str);
+        let result = CommentAttributes::evaluate_include_str(&path_str).unwrap();
 
         // TempDir will automatically clean up the directory and its contents when it goes out of scope
         assert_eq!(result, test_content);
     }
+
+    #[test]
+    fn test_doc_sources_separated_with_blank_line() {
+        let tmp_dir = TempDir::new("doc_test").unwrap();
+        let file_path = tmp_dir.path().join("overview.md");
+        {
+            let mut file = File::create(&file_path).unwrap();
+            write!(file, "# Overview\nIncluded content").unwrap();
+        }
+        let include: Attribute = syn::parse_str::<syn::DeriveInput>(&format!(
+            "#[doc = include_str!({:?})] struct Foo;",
+            file_path.to_str().unwrap()
+        ))
+        .unwrap()
+        .attrs
+        .remove(0);
+
+        let attributes: Vec<Attribute> = vec![
+            parse_quote!(#[doc = "First line"]),
+            parse_quote!(#[doc = "second line"]),
+            include,
+            parse_quote!(#[doc = "Last line"]),
+        ];
+        let comments = CommentAttributes::from_attributes(&attributes);
+
+        assert_eq!(
+            comments.as_formatted_string(),
+            "First line\nsecond line\n\n# Overview\nIncluded content\n\nLast line"
+        );
+    }
+
+    #[test]
+    fn test_included_file_single_trailing_newline_trimmed() {
+        let tmp_dir = TempDir::new("doc_test").unwrap();
+        let include = |name: &str, content: &str| -> Attribute {
+            let file_path = tmp_dir.path().join(name);
+            std::fs::write(&file_path, content).unwrap();
+            syn::parse_str::<syn::DeriveInput>(&format!(
+                "#[doc = include_str!({:?})] struct Foo;",
+                file_path.to_str().unwrap()
+            ))
+            .unwrap()
+            .attrs
+            .remove(0)
+        };
+
+        for (name, (content, expected)) in [
+            ("# Title\nContent\n", "# Title\nContent\n\nLast line"),
+            ("# Title\nContent", "# Title\nContent\n\nLast line"),
+            ("# Title\nContent\n\n", "# Title\nContent\n\nLast line"),
+            ("# Title\n\nContent\n", "# Title\n\nContent\n\nLast line"),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(index, case)| (format!("overview_{index}.md"), case))
+        {
+            let attributes: Vec<Attribute> =
+                vec![include(&name, content), parse_quote!(#[doc = "Last line"])];
+            let comments = CommentAttributes::from_attributes(&attributes);
+            assert_eq!(comments.as_formatted_string(), expected, "{content:?}");
+        }
+
+        let comments =
+            CommentAttributes::from_attributes(&[include("only.md", "# Title\nContent\n")]);
+        assert_eq!(comments.as_formatted_string(), "# Title\nContent");
+    }
+
+    #[test]
+    fn test_included_file_read_from_cache_until_modified() {
+        let tmp_dir = TempDir::new("doc_test").unwrap();
+        let file_path = tmp_dir.path().join("cached.md");
+        std::fs::write(&file_path, "First content").unwrap();
+        let modified = std::fs::metadata(&file_path).unwrap().modified().unwrap();
+
+        assert_eq!(
+            CommentAttributes::read_included_file(&file_path).unwrap(),
+            "First content"
+        );
+
+        // cached content is returned while the modification time stays the same
+        std::fs::write(&file_path, "Second content").unwrap();
+        File::options()
+            .write(true)
+            .open(&file_path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        assert_eq!(
+            CommentAttributes::read_included_file(&file_path).unwrap(),
+            "First content"
+        );
+
+        File::options()
+            .write(true)
+            .open(&file_path)
+            .unwrap()
+            .set_modified(modified + std::time::Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(
+            CommentAttributes::read_included_file(&file_path).unwrap(),
+            "Second content"
+        );
+    }
+
+    #[test]
+    fn test_windows_line_endings_normalized() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc = " line\r"]),
+            parse_quote!(#[doc = " break  \r\n next\r\n"]),
+        ];
+        let comments = CommentAttributes::from_attributes(&attrs);
+        assert_eq!(comments.as_formatted_string(), "line\nbreak  \nnext");
+
+        let tmp_dir = TempDir::new("doc_test").unwrap();
+        std::fs::write(
+            tmp_dir.path().join("windows.md"),
+            "# Title\r\n\r\nContent\r\n",
+        )
+        .unwrap();
+        let path_str = format!("\"{}\"", tmp_dir.path().join("windows.md").display());
+        let result = CommentAttributes::evaluate_include_str(&path_str).unwrap();
+        assert_eq!(result, "# Title\n\nContent\n");
+    }
+
+    #[test]
+    fn test_concat_path_with_multiple_segments() {
+        let tmp_dir = TempDir::new("doc_test").unwrap();
+        let test_content = "Concat content";
+        std::fs::create_dir(tmp_dir.path().join("docs")).unwrap();
+        {
+            let mut file = File::create(tmp_dir.path().join("docs").join("api.md")).unwrap();
+            write!(file, "{}", test_content).unwrap();
+        }
+
+        std::env::set_var("UTOIPA_DOC_CONCAT_TEST_DIR", tmp_dir.path());
+
+        let result = CommentAttributes::evaluate_include_str(
+            "concat!(env!(\"UTOIPA_DOC_CONCAT_TEST_DIR\", \"not set\"), \"/docs/\", concat!(\"api\", \".md\"))",
+        )
+        .unwrap();
+
+        assert_eq!(result, test_content);
+    }
+
+    #[test]
+    fn test_env_var_path_resolves_any_variable() {
+        let tmp_dir = TempDir::new("doc_test").unwrap();
+        std::fs::write(tmp_dir.path().join("generated.md"), "Generated content").unwrap();
+
+        std::env::set_var("UTOIPA_DOC_OUT_DIR_TEST", tmp_dir.path());
+
+        let result = CommentAttributes::evaluate_include_str(
+            "concat!(env!(\"UTOIPA_DOC_OUT_DIR_TEST\"), \"/generated.md\")",
+        )
+        .unwrap();
+
+        assert_eq!(result, "Generated content");
+    }
+
+    #[test]
+    fn test_resolve_include_path() {
+        std::env::set_var("UTOIPA_DOC_RESOLVE_TEST_DIR", "/project/target/out");
+
+        for (path_str, expected) in [
+            ("\"docs/api.md\"", "docs/api.md"),
+            ("\"/docs/api.md\"", "/docs/api.md"),
+            ("concat!(\"docs/\", concat!(\"api\", \".md\"))", "docs/api.md"),
+            (
+                "concat!(env!(\"UTOIPA_DOC_RESOLVE_TEST_DIR\"), \"/generated.md\")",
+                "/project/target/out/generated.md",
+            ),
+            (
+                "concat!(env!(\"UTOIPA_DOC_RESOLVE_TEST_DIR\", \"not set\"), \"/docs/\", \"api.md\")",
+                "/project/target/out/docs/api.md",
+            ),
+        ] {
+            assert_eq!(
+                CommentAttributes::resolve_include_path(path_str).unwrap(),
+                PathBuf::from(expected),
+                "{path_str}"
+            );
+        }
+
+        assert!(CommentAttributes::resolve_include_path("concat!(1, 2)").is_err());
+    }
+
+    #[test]
+    fn test_env_var_path_unset_variable_fails() {
+        let error = CommentAttributes::evaluate_include_str(
+            "concat!(env!(\"UTOIPA_DOC_UNSET_TEST_VAR\"), \"/generated.md\")",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            "environment variable `UTOIPA_DOC_UNSET_TEST_VAR` not defined at compile time, used in include_str! path"
+        );
+
+        let error = CommentAttributes::evaluate_include_str(
+            "concat!(env!(\"UTOIPA_DOC_UNSET_TEST_VAR\", \"run build script first\"), \"/generated.md\")",
+        )
+        .unwrap_err();
+
+        assert_eq!(error, "run build script first");
+    }
+
+    #[cfg(feature = "localized_docs")]
+    #[test]
+    fn test_localized_docs() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc = "Get items"]),
+            parse_quote!(#[utoipa_doc(locale = "de", text = "Elemente abrufen")]),
+            parse_quote!(#[utoipa_doc(locale = "fi", text = "Hae kohteet")]),
+            parse_quote!(#[utoipa_doc(locale = "de", text = "Zweite Zeile")]),
+            parse_quote!(#[utoipa_doc(locale = "sv")]),
+        ];
+
+        let comments = CommentAttributes::from_attributes(&attrs);
+
+        assert_eq!(comments.as_formatted_string(), "Get items");
+        assert_eq!(
+            comments.localized("de").as_deref(),
+            Some("Elemente abrufen\nZweite Zeile")
+        );
+        assert_eq!(comments.localized("fi").as_deref(), Some("Hae kohteet"));
+        assert_eq!(comments.localized("sv"), None);
+        assert_eq!(comments.locales().collect::<Vec<_>>(), ["de", "fi"]);
+    }
 }
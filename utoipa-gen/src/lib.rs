@@ -221,7 +221,8 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// * `xml(name = "...")` Will set name for property or type.
 /// * `xml(namespace = "...")` Will set namespace for xml element which needs to be valid uri.
 /// * `xml(prefix = "...")` Will set prefix for name.
-/// * `xml(attribute)` Will translate property to xml attribute instead of xml element.
+/// * `xml(attribute)` Will translate property to xml attribute instead of xml element. Cannot be
+///   used with `Vec` types.
 /// * `xml(wrapped)` Will make wrapped xml element.
 /// * `xml(wrapped(name = "wrap_name"))` Will override the wrapper elements name.
 ///
@@ -494,6 +495,9 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///
 /// Other _`serde`_ attributes works as is but does not have any effect on the generated OpenAPI doc.
 ///
+/// Named fields hidden from rustdoc with _`#[doc(hidden)]`_ are skipped the same way as with
+/// _`#[serde(skip)]`_.
+///
 /// **Note!** `tag` attribute has some limitations like it cannot be used with **tuple types**. See more at
 /// [enum representation docs](https://serde.rs/enum-representations.html).
 ///
@@ -978,9 +982,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// You can use the Rust's own `#[deprecated]` attribute on functions to mark it as deprecated and it will
 /// reflect to the generated OpenAPI spec. Only **parameters** has a special **deprecated** attribute to define them as deprecated.
 ///
-/// `#[deprecated]` attribute supports adding additional details such as a reason and or since version but this is is not supported in
-/// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. While it is totally okay to declare deprecated with reason
-/// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
+/// `#[deprecated]` attribute supports adding additional details such as a reason and or since version but OpenAPI has only
+/// a boolean flag to determine deprecation. The reason given with `#[deprecated = "There is better way to do this"]` or
+/// `#[deprecated(note = "There is better way to do this")]` is added to _`x-deprecated-reason`_ extension of the operation.
 ///
 /// Doc comment at decorated function will be used for _`description`_ and _`summary`_ of the path.
 /// First line of the doc comment will be used as the _`summary`_ while the remaining lines will be
@@ -1310,8 +1314,8 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// # Params Attributes
 ///
-/// The list of attributes inside the `params(...)` attribute can take two forms: [Tuples](#tuples) or [IntoParams
-/// Type](#intoparams-type).
+/// The list of attributes inside the `params(...)` attribute can take three forms: [Tuples](#tuples), [IntoParams
+/// Type](#intoparams-type) or [Reference](#parameter-reference).
 ///
 /// ## Tuples
 ///
@@ -1322,27 +1326,144 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `parameter_type` Define possible type for the parameter. Can be `Type` or `inline(Type)`.
 ///   The given _`Type`_ can be any Rust type that is JSON parseable. It can be Option, Vec or Map etc.
+///   Slices _`[T]`_ are treated same as _`Vec<T>`_ and references e.g. _`&str`_ same as the
+///   referenced type.
 ///   With _`inline(...)`_ the schema will be inlined instead of a referenced which is the default for
 ///   [`ToSchema`][to_schema] types. Parameter type is placed after `name` with
-///   equals sign E.g. _`"id" = string`_
+///   equals sign E.g. _`"id" = string`_.
+///   When _`parameter_type`_ is not defined and cannot be resolved otherwise the parameter
+///   defaults to _`string`_ schema. Map types of _`Path`_ and _`Query`_ parameters require
+///   explicit _`style`_ e.g. _`style = DeepObject`_ and collections of maps are not supported.
 ///
 /// * `in` _**Must be placed after name or parameter_type**_. Define the place of the parameter.
 ///   This must be one of the variants of [`openapi::path::ParameterIn`][in_enum].
-///   E.g. _`Path, Query, Header, Cookie`_
+///   E.g. _`Path, Query, Header, Cookie`_. When _`in`_ is not defined and it cannot be resolved
+///   from handler arguments the parameter defaults to _`Path`_ e.g. _`("id" = i32)`_ is same as
+///   _`("id" = i32, Path)`_. When _`path`_ is a string literal
+///   _`Path`_ parameters must be found as _`{name}`_ segments of it and parameters in other
+///   locations must not collide with the segments.
+///   Non-standard locations e.g. _`body`_ of legacy API definitions can be defined with quoted
+///   location string _`in = "body"`_ which is used as is. Standard locations are resolved to
+///   their variants e.g. _`in = "query"`_ is same as _`Query`_.
 ///
 /// * `deprecated` Define whether the parameter is deprecated or not. Can optionally be defined
-///    with explicit `bool` value as _`deprecated = bool`_.
+///   with explicit `bool` value as _`deprecated = bool`_. Deprecation reason can be given as
+///   string _`deprecated = "use cursor instead"`_ which marks the parameter deprecated and adds
+///   the reason to _`x-deprecated-reason`_ extension, since OpenAPI has no field for it.
+///   If not defined, the parameter is deprecated when its type is a deprecated `ToSchema` type
+///   e.g. a struct with `#[deprecated]`. Explicitly defined `deprecated` always wins, thus
+///   _`deprecated = false`_ keeps the parameter non-deprecated regardless of its type.
+///
+/// * `since = "..."` Define version since which the parameter has been deprecated. The version
+///   is added to _`x-deprecated-since`_ extension. Can only be used with deprecated parameter
+///   e.g. _`deprecated, since = "2.3.0"`_.
+///
+/// * `description = ...` Define possible description for the parameter. Value can be [`str`] or
+///   an expression such as [`include_str!`][include_str] or static [`const`][const] reference.
 ///
-/// * `description = "..."` Define possible description for the parameter as str.
+/// * `description_template = "..."` Define description with _`{name}`_ placeholder which is
+///   substituted with the name of the parameter, e.g. _`description_template = "The {name} of the
+///   item"`_. Only _`{name}`_ is substituted and other braces are kept as is. Cannot be used
+///   together with _`description`_.
+///
+/// * `description_ref = "..."` Use description of another parameter of the same _`params(...)`_
+///   by its name e.g. _`description_ref = "page"`_ to avoid repeating shared descriptions.
+///   Referenced _`description_template`_ is expanded with the name of the referencing parameter.
+///   Cannot be used together with _`description`_ or _`description_template`_.
+///
+/// * `required = ...` Can be used to enforce required status for the parameter. Can optionally
+///   be defined with explicit `bool` value as _`required = bool`_. By default the required status
+///   is derived from the _`parameter_type`_ where _`Option<T>`_ is not required. When defined the
+///   explicit value takes precedence over the type derived value. Parameters with _`in`_
+///   _`Path`_ are always required regardless of this attribute.
 ///
 /// * `style = ...` Defines how parameters are serialized by [`ParameterStyle`][style]. Default values are based on _`in`_ attribute.
+///   The style can be given either as variant of [`ParameterStyle`][style] e.g. _`style = Form`_ or
+///   as the OpenAPI style name string e.g. _`style = "spaceDelimited"`_. _`Matrix`_ and _`Label`_
+///   styles are only allowed for _`Path`_ parameters, _`Form`_ style is only allowed for _`Query`_
+///   and _`Cookie`_ parameters and _`SpaceDelimited`_, _`PipeDelimited`_ and _`DeepObject`_ styles
+///   are only allowed for _`Query`_ parameters. _`DeepObject`_ style is only allowed for object
+///   types e.g. _`("filter" = Filter, Query, style = DeepObject, explode)`_. Object parameter with
+///   _`style = Form, explode`_ is serialized with each property as separate query key.
+///   Array _`Cookie`_ parameters e.g. _`("prefs" = Vec<String>, Cookie)`_ are serialized with
+///   _`style = Form`_ and _`explode = false`_ as comma separated values of a single cookie unless
+///   _`style`_ or _`explode`_ is explicitly defined.
 ///
 /// * `explode` Defines whether new _`parameter=value`_ is created for each parameter within _`object`_ or _`array`_.
 ///
 /// * `allow_reserved` Defines whether reserved characters _`:/?#[]@!$&'()*+,;=`_ is allowed within value.
-///
-/// * `example = ...` Can method reference or _`json!(...)`_. Given example
-///   will override any example in underlying parameter type.
+///   This is only applicable to parameters with _`in`_ _`Query`_.
+///
+/// * `allow_empty_value` Defines whether the parameter can be sent without a value e.g.
+///   _`?verbose`_. Can optionally be defined with explicit `bool` value as
+///   _`allow_empty_value = bool`_. Only allowed for _`Query`_ parameters.
+///
+/// * `example_url = "..."` Define fully qualified example URL of the parameter e.g. for resource
+///   ids _`example_url = "https://api.example.com/items/1"`_. The URL must start with a scheme
+///   and it is added to _`x-example-url`_ extension.
+///
+/// * `x_samesite = ...` Define _`SameSite`_ attribute of a cookie as one of _`"Strict"`_,
+///   _`"Lax"`_ or _`"None"`_. It is added to _`x-samesite`_ extension of the parameter since
+///   OpenAPI has no field for it. Only allowed for _`Cookie`_ parameters.
+///
+/// * `x_http_only` Define that a cookie has _`HttpOnly`_ attribute. It is added to
+///   _`x-http-only`_ extension of the parameter. Can optionally be defined with explicit `bool`
+///   value as _`x_http_only = bool`_. Only allowed for _`Cookie`_ parameters.
+///
+/// * `example = ...` Can be any value e.g. literal, method reference or _`json!(...)`_. Given example
+///   will override any example in underlying parameter type. Without _`example`_ or _`examples`_
+///   the parameter does not define example of its own and the example of the parameter type
+///   e.g. _`#[schema(example = ...)]`_ of a _`ToSchema`_ type is inherited from its schema, thus
+///   there is no need to duplicate it to the parameter.
+///   Multiline or JSON text examples can be given as raw string literal e.g.
+///   _`example = r#"{ "a": 1 }"#`_ which is used as string example verbatim.
+///
+/// * `examples(...)` Define multiple named examples for the parameter. This attribute is mutually
+///   exclusive to the _`example`_ attribute. This has same syntax as _`examples(...)`_ in
+///   [Response Attributes](#response-attributes) _examples(...)_
+///
+/// * `no_schema` Opt-out from the default _`string`_ schema of parameter without
+///   _`parameter_type`_. Can optionally be defined with explicit `bool` value as
+///   _`no_schema = bool`_.
+///
+/// * `schema_type = "..."` Override the _`type`_ of the parameter schema instead of resolving it
+///   from the _`parameter_type`_, e.g. _`("token" = Token, Header, schema_type = "string")`_ for
+///   newtype wrappers without [`ToSchema`][to_schema] implementation. Must be one of _`string`_,
+///   _`integer`_, _`number`_, _`boolean`_ or _`object`_.
+///
+/// * `boolean` Shorthand for _`schema_type = "boolean"`_ for boolean flag parameters e.g.
+///   _`("active", Query, boolean)`_. This is mutually exclusive to the _`schema_type`_ attribute.
+///
+/// * `alias = "..."` Define alternate spelling for the parameter name e.g. for case-insensitive
+///   _`Header`_ parameters. Can be defined multiple times. Aliases are added to _`x-aliases`_
+///   extension while the _`name`_ is kept as is.
+///
+/// * `extensions("x-name" = value, ...)` Define OpenAPI vendor extensions for the parameter e.g.
+///   _`extensions("x-gateway" = "internal", "x-rate-limit" = 100)`_. Extension names must start
+///   with _`x-`_ and values can be literal strings, numbers or booleans.
+///
+/// * `rename = "..."` Define name of the parameter in OpenAPI documentation e.g.
+///   _`("page_size", Query, rename = "pageSize")`_. The original _`name`_ is still used to match
+///   the parameter with handler arguments while the renamed value is documented and used to
+///   find the parameter from the _`path`_.
+///
+/// * `content = { "media/type": Type }` Define media type of the parameter with its own schema
+///   type e.g. _`content = { "application/json": inline(Filter) }`_. As defined by the OpenAPI
+///   specification _`content`_ can only have one media type. Parameter with _`content`_ cannot
+///   have _`parameter_type`_ or [parameter type attributes](#parameter-type-attributes).
+///   For media type of the parameter schema see _`content_type`_.
+///
+/// * `encoding = { "property": { ... }, ... }` Define encoding of the properties of the parameter
+///   _`content`_ e.g. _`encoding = { "tags": { style = Form, explode = false } }`_. Each property
+///   can define _`content_type = "..."`_, _`style = ...`_, _`explode`_ and _`allow_reserved`_ in
+///   the same format as the parameter attributes of same name. Encoding is added to the media
+///   type of the parameter, thus it can only be used with _`content_type`_ or _`content`_.
+///
+/// * `schema_ref = "Name"` Use _`$ref`_ to the given component schema e.g.
+///   _`#/components/schemas/Status`_ as the parameter schema instead of the schema of the
+///   _`parameter_type`_. Required status is still resolved from the _`parameter_type`_ if defined.
+///   This cannot be used with _`inline(...)`_ type, _`content`_ or
+///   [parameter type attributes](#parameter-type-attributes).
 ///
 /// ##### Parameter type attributes
 ///
@@ -1352,16 +1473,24 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `format = ...` May either be variant of the [`KnownFormat`][known_format] enum, or otherwise
 ///   an open value as a string. By default the format is derived from the type of the property
-///   according OpenApi spec.
+///   according OpenApi spec. Numeric formats such as `Int64` or `"double"` are only allowed on
+///   number types and string formats such as `DateTime` or `"uuid"` only on string types.
 ///
 /// * `write_only` Defines property is only used in **write** operations *POST,PUT,PATCH* but not in *GET*
 ///
 /// * `read_only` Defines property is only used in **read** operations *GET* but not in *POST,PUT,PATCH*
+///   This is mutually exclusive to _`write_only`_.
 ///
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties for the parameter type.
 ///    See configuration options at xml attributes of [`ToSchema`][to_schema_xml]
 ///
-/// * `nullable` Defines property is nullable (note this is different to non-required).
+/// * `nullable` Defines property is nullable (note this is different to non-required). Can
+///   optionally be defined with explicit `bool` value as _`nullable = bool`_. Nullability is
+///   independent of the _`Option`_ type thus parameter can be both _`required`_ and _`nullable`_
+///   for APIs accepting explicit _`null`_ value. _`Option`_ parameter types are nullable by
+///   default by adding _`null`_ to the schema _`type`_ since OpenAPI 3.1 has no _`nullable`_
+///   keyword. Use _`nullable = false`_ when absence of the parameter is the only way to omit the
+///   value e.g. _`("size" = Option<i32>, Query, nullable = false)`_.
 ///
 /// * `multiple_of = ...` Can be used to define multiplier for a value. Value is considered valid
 ///   division will result an `integer`. Value must be strictly above _`0`_.
@@ -1374,6 +1503,13 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `exclusive_minimum = ...` Can be used to define exclusive lower bound to a `number` value.
 ///
+///   Exclusive bounds are emitted as numbers _`exclusiveMinimum: 0`_ as defined by OpenAPI 3.1
+///   which is the version generated by `utoipa`. The OpenAPI 3.0 boolean form
+///   _`minimum: 0, exclusiveMinimum: true`_ is not supported.
+///
+///   Numeric bounds cannot be used with _`date`_ or _`date-time`_ format, use _`pattern`_ to
+///   restrict dates instead.
+///
 /// * `max_length = ...` Can be used to define maximum length for `string` types.
 ///
 /// * `min_length = ...` Can be used to define minimum length for `string` types.
@@ -1386,12 +1522,47 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `min_items = ...` Can be used to define minimum items allowed for `array` fields. Value must
 ///   be non-negative integer.
 ///
+/// * `unique_items` Can be used to define that items of `array` fields must be unique. Can optionally
+///   be defined with explicit `bool` value as _`unique_items = bool`_.
+///
+/// * `content_type = "..."` Can be used to serialize the parameter type under _`content`_ with
+///   given media type instead of _`schema`_ e.g. _`content_type = "application/json"`_ for JSON
+///   encoded query parameter. Value must be a valid media type.
+///
+/// * `default = ...` Can be used to define default value for the parameter schema. Value can be
+///   any literal e.g. _`default = 20`_ or _`default = "asc"`_. The value is required. Defining
+///   default does not change the required status of the parameter.
+///
+/// * `const = ...` Can be used to restrict the value to single fixed value which is added as
+///   JSON Schema _`const`_ to the parameter schema e.g. _`("X-API-Version" = String, Header, const = "2")`_.
+///
+/// * `enum_values = [...]` Can be used to restrict the value to given list of literal values
+///   e.g. _`enum_values = ["asc", "desc"]`_. For `array` types the values apply to the items.
+///   Empty list will not restrict the value. Values must be compatible with the parameter type,
+///   e.g. string values are only allowed for `string` types and integer values for `integer` and
+///   `number` types.
+///
 /// ##### Parameter Formats
 /// ```test
 /// ("name" = ParameterType, ParameterIn, ...)
 /// ("name", ParameterIn, ...)
 /// ```
 ///
+/// Multiple parameters sharing the same _`in`_ can be grouped in single tuple where the leading
+/// _`in`_ is applied to all following parameters. Grouped parameters only support _`name`_ and
+/// optional _`parameter_type`_.
+/// ```text
+/// (ParameterIn, "name" = ParameterType, "name", ...)
+/// ```
+///
+/// Grouped parameters which must be provided either all together or not at all can be marked with
+/// _`required_group = "name"`_ placed after the _`in`_. OpenAPI has no construct for this thus the
+/// group name is added to _`x-required-group`_ extension of each parameter. The group must
+/// contain at least two parameters and the same group can only be defined once.
+/// ```text
+/// (Query, required_group = "dates", "from" = String, "to" = String)
+/// ```
+///
 /// **For example:**
 ///
 /// ```text
@@ -1400,6 +1571,7 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///     ("x-custom-header" = String, Header, description = "Custom header"),
 ///     ("id" = String, Path, deprecated, description = "Pet database id"),
 ///     ("name", Path, deprecated, description = "Pet name"),
+///     (Query, "page" = i32, "size" = i32),
 ///     (
 ///         "value" = inline(Option<[String]>),
 ///         Query,
@@ -1435,6 +1607,19 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// )
 /// ```
 ///
+/// ## Parameter Reference
+///
+/// Reusable parameter added to `components.parameters` can be referenced with `ref = "Name"`
+/// syntax. This will render as _`$ref: "#/components/parameters/Name"`_ instead of inlining the
+/// parameter.
+///
+/// ```text
+/// params(
+///     ref = "Page",
+///     ("id" = String, Path, description = "Pet database id"),
+/// )
+/// ```
+///
 /// # Security Requirement Attributes
 ///
 /// * `name` Define the name for security requirement. This must match to name of existing
@@ -1543,7 +1728,7 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// /// Get todo by id and name.
 /// #[utoipa::path(
 ///     get,
-///     path = "/todo/{id}",
+///     path = "/todo/{id}/{name}",
 ///     params(
 ///         ("id", description = "Todo id"),
 ///         ("name", description = "Todo name")
@@ -1941,8 +2126,9 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
     let path = Path::new(path_attribute, &ast_fn.sig.ident)
         .ext_methods(resolved_methods.map(|operation| operation.methods))
         .path(resolved_path.map(|path| path.path))
-        .doc_comments(CommentAttributes::from_attributes(&ast_fn.attrs).0)
-        .deprecated(ast_fn.attrs.has_deprecated());
+        .doc_comments(CommentAttributes::from_attributes(&ast_fn.attrs))
+        .deprecated(ast_fn.attrs.has_deprecated())
+        .deprecated_note(ast_fn.attrs.deprecated_note());
 
     let handler = path::handler::Handler {
         path,
@@ -2221,7 +2407,14 @@ pub fn openapi(input: TokenStream) -> TokenStream {
         .into()
 }
 
-#[proc_macro_derive(IntoParams, attributes(param, into_params))]
+#[cfg_attr(
+    feature = "localized_docs",
+    proc_macro_derive(IntoParams, attributes(param, into_params, utoipa_doc))
+)]
+#[cfg_attr(
+    not(feature = "localized_docs"),
+    proc_macro_derive(IntoParams, attributes(param, into_params))
+)]
 /// Generate [path parameters][path_params] from struct's
 /// fields.
 ///
@@ -2236,9 +2429,15 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// deprecated and it will reflect to the generated OpenAPI spec.
 ///
 /// `#[deprecated]` attribute supports adding additional details such as a reason and or since version
-/// but this is is not supported in OpenAPI. OpenAPI has only a boolean flag to determine deprecation.
-/// While it is totally okay to declare deprecated with reason
-/// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
+/// but OpenAPI has only a boolean flag to determine deprecation. The reason given with
+/// `#[deprecated = "There is better way to do this"]` or
+/// `#[deprecated(note = "There is better way to do this")]` is added to _`x-deprecated-reason`_
+/// extension of the parameter.
+///
+/// With **`localized_docs`** feature translations of the field doc comments can be given with
+/// _`#[utoipa_doc(locale = "de", text = "...")]`_ attributes. Each attribute is a single line of
+/// the translation and the translations are added to _`x-translations`_ extension of the parameter
+/// by the locale e.g. _`{"de": "..."}`_.
 ///
 /// Doc comment on struct fields will be used as description for the generated parameters.
 /// ```rust
@@ -2295,6 +2494,15 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// * `example = ...` Can be method reference or _`json!(...)`_. Given example
 ///   will override any example in underlying parameter type.
 ///
+/// * `description = ...` Can be literal string or Rust expression e.g. _`const`_ reference or
+///   `include_str!(...)` statement. By default the description is derived from the field doc comments.
+///   When defined the explicit description will override the doc comments.
+///
+/// * `no_description` Opt-out from the description derived from the field doc comments e.g. when
+///   the doc comments are internal notes not meant for the public API documentation. Can optionally
+///   be defined with explicit `bool` value as _`no_description = bool`_. This cannot be used
+///   together with _`description = ...`_.
+///
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
 ///   This is useful in cases where the default type does not correspond to the actual type e.g. when
 ///   any third-party types are used which are not [`ToSchema`][to_schema]s nor [`primitive` types][primitive].
@@ -2349,6 +2557,13 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// * `min_items = ...` Can be used to define minimum items allowed for `array` fields. Value must
 ///   be non-negative integer.
 ///
+/// * `unique_items` Can be used to define that items of `array` fields must be unique. Can optionally
+///   be defined with explicit `bool` value as _`unique_items = bool`_.
+///
+/// * `enum_values = [...]` Can be used to restrict the value to given list of literal values
+///   e.g. _`enum_values = ["asc", "desc"]`_. For `array` types the values apply to the items.
+///   Empty list will not restrict the value.
+///
 /// * `schema_with = ...` Use _`schema`_ created by provided function reference instead of the
 ///   default derived _`schema`_. The function must match to `fn() -> Into<RefOr<Schema>>`. It does
 ///   not accept arguments and must return anything that can be converted into `RefOr<Schema>`.
@@ -2382,6 +2597,9 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 ///
 /// Other _`serde`_ attributes will impact the serialization but will not be reflected on the generated OpenAPI doc.
 ///
+/// Fields hidden from rustdoc with _`#[doc(hidden)]`_ are skipped the same way as with
+/// _`#[serde(skip)]`_.
+///
 /// # Examples
 ///
 /// _**Demonstrate [`IntoParams`][into_params] usage with resolving `Path` and `Query` parameters
@@ -3600,6 +3818,10 @@ impl FromIterator<Diagnostics> for Option<Diagnostics> {
 
 trait AttributesExt {
     fn has_deprecated(&self) -> bool;
+
+    /// Get the note of Rust `#[deprecated]` attribute defined either with
+    /// `#[deprecated = "note"]` or `#[deprecated(note = "note")]` syntax.
+    fn deprecated_note(&self) -> Option<String>;
 }
 
 impl AttributesExt for Vec<syn::Attribute> {
@@ -3607,6 +3829,11 @@ impl AttributesExt for Vec<syn::Attribute> {
         let this = &**self;
         this.has_deprecated()
     }
+
+    fn deprecated_note(&self) -> Option<String> {
+        let this = &**self;
+        this.deprecated_note()
+    }
 }
 
 impl<'a> AttributesExt for &'a [syn::Attribute] {
@@ -3615,6 +3842,33 @@ impl<'a> AttributesExt for &'a [syn::Attribute] {
             matches!(attr.path().get_ident(), Some(ident) if &*ident.to_string() == "deprecated")
         })
     }
+
+    fn deprecated_note(&self) -> Option<String> {
+        self.iter()
+            .filter(|attr| attr.path().is_ident("deprecated"))
+            .find_map(|attr| match &attr.meta {
+                syn::Meta::NameValue(name_value) => match &name_value.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(note),
+                        ..
+                    }) => Some(note.value()),
+                    _ => None,
+                },
+                syn::Meta::List(list) => list
+                    .parse_args_with(Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated)
+                    .ok()?
+                    .into_iter()
+                    .find(|name_value| name_value.path.is_ident("note"))
+                    .and_then(|name_value| match name_value.value {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: Lit::Str(note),
+                            ..
+                        }) => Some(note.value()),
+                        _ => None,
+                    }),
+                syn::Meta::Path(_) => None,
+            })
+    }
 }
 
 #[cfg(test)]
@@ -3641,7 +3895,7 @@ mod tests {
 mod parse_utils {
     use std::fmt::Display;
 
-    use proc_macro2::{Group, Ident, TokenStream};
+    use proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
     use quote::{quote, ToTokens};
     use syn::{
         parenthesized,
@@ -3649,7 +3903,7 @@ mod parse_utils {
         punctuated::Punctuated,
         spanned::Spanned,
         token::Comma,
-        Error, Expr, ExprPath, LitBool, LitStr, Token,
+        Error, Expr, ExprPath, Lit, LitBool, LitStr, Token,
     };
 
     #[cfg_attr(feature = "debug", derive(Debug))]
@@ -3682,7 +3936,18 @@ mod parse_utils {
             if input.peek(LitStr) {
                 Ok::<LitStrOrExpr, Error>(LitStrOrExpr::LitStr(input.parse::<LitStr>()?))
             } else {
-                Ok(LitStrOrExpr::Expr(input.parse::<Expr>()?))
+                let found = describe_next_token(input);
+                match input.parse::<Expr>() {
+                    Ok(Expr::Lit(lit)) => Err(Error::new(
+                        lit.span(),
+                        format!("expected literal string or expression argument, found {found}"),
+                    )),
+                    Ok(expr) => Ok(LitStrOrExpr::Expr(expr)),
+                    Err(error) => Err(Error::new(
+                        error.span(),
+                        format!("expected literal string or expression argument: {error}"),
+                    )),
+                }
             }
         }
     }
@@ -3713,17 +3978,63 @@ mod parse_utils {
         next()
     }
 
+    /// Describe the next token of the `input` for error messages, e.g. `integer literal`.
+    pub fn describe_next_token(input: ParseStream) -> String {
+        match input.cursor().token_tree() {
+            Some((TokenTree::Literal(literal), _)) => match Lit::new(literal) {
+                Lit::Str(_) => "literal string".to_string(),
+                Lit::ByteStr(_) => "byte string literal".to_string(),
+                Lit::Byte(_) => "byte literal".to_string(),
+                Lit::Char(_) => "character literal".to_string(),
+                Lit::Int(_) => "integer literal".to_string(),
+                Lit::Float(_) => "float literal".to_string(),
+                _ => "literal".to_string(),
+            },
+            Some((TokenTree::Ident(ident), _)) if ident == "true" || ident == "false" => {
+                "boolean literal".to_string()
+            }
+            Some((TokenTree::Ident(ident), _)) => format!("identifier `{ident}`"),
+            Some((TokenTree::Punct(punct), _)) => format!("`{}`", punct.as_char()),
+            Some((TokenTree::Group(group), _)) => match group.delimiter() {
+                Delimiter::Parenthesis => "parenthesized group".to_string(),
+                Delimiter::Bracket => "array".to_string(),
+                Delimiter::Brace => "block".to_string(),
+                Delimiter::None => "group".to_string(),
+            },
+            None => "end of input".to_string(),
+        }
+    }
+
+    /// Create error pointing at the next token of the `input` with message `{expected}, found {token}`.
+    pub fn unexpected_value_error(input: ParseStream, expected: &str) -> Error {
+        Error::new(
+            input.span(),
+            format!("{expected}, found {}", describe_next_token(input)),
+        )
+    }
+
+    /// Combine `errors` to single [`Error`] reporting all of them, [`None`] if there are no errors.
+    pub fn combine_errors(errors: impl IntoIterator<Item = Error>) -> Option<Error> {
+        errors.into_iter().reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        })
+    }
+
+    pub fn parse_literal_str(input: ParseStream) -> syn::Result<LitStr> {
+        if input.peek(LitStr) {
+            input.parse::<LitStr>()
+        } else {
+            Err(unexpected_value_error(input, "expected literal string"))
+        }
+    }
+
     pub fn parse_next_literal_str(input: ParseStream) -> syn::Result<String> {
-        Ok(parse_next(input, || input.parse::<LitStr>())?.value())
+        Ok(parse_next(input, || parse_literal_str(input))?.value())
     }
 
     pub fn parse_next_literal_str_or_expr(input: ParseStream) -> syn::Result<LitStrOrExpr> {
-        parse_next(input, || LitStrOrExpr::parse(input)).map_err(|error| {
-            syn::Error::new(
-                error.span(),
-                format!("expected literal string or expression argument: {error}"),
-            )
-        })
+        parse_next(input, || LitStrOrExpr::parse(input))
     }
 
     pub fn parse_groups_collect<T, R>(input: ParseStream) -> syn::Result<R>
@@ -3776,6 +4087,10 @@ mod parse_utils {
             input.parse::<Token![=]>()?;
 
             Ok(input.parse::<LitBool>()?.value())
+        } else if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+
+            Err(unexpected_value_error(input, "expected literal boolean"))
         } else {
             Ok(true)
         }
@@ -11,13 +11,18 @@ use syn::{parenthesized, parse::Parse, Token};
 use syn::{Expr, ExprLit, Lit, LitStr};
 
 use crate::component::{ComponentSchema, GenericType, TypeTree};
+use crate::doc_comment::CommentAttributes;
 use crate::{
     as_tokens_or_diagnostics, parse_utils, Deprecated, Diagnostics, OptionExt, ToTokensDiagnostics,
 };
 use crate::{schema_type::SchemaType, security_requirement::SecurityRequirementsAttr, Array};
 
 use self::response::Response;
-use self::{parameter::Parameter, request_body::RequestBodyAttr, response::Responses};
+use self::{
+    parameter::{Parameter, ParameterList},
+    request_body::RequestBodyAttr,
+    response::Responses,
+};
 
 pub mod example;
 pub mod handler;
@@ -48,6 +53,8 @@ pub struct PathAttr<'p> {
     tag: Option<parse_utils::LitStrOrExpr>,
     tags: Vec<parse_utils::LitStrOrExpr>,
     params: Vec<Parameter<'p>>,
+    /// Number of leading `params` written in `params(...)` before parameters from extensions.
+    attr_params_len: usize,
     security: Option<Array<'p, SecurityRequirementsAttr>>,
     context_path: Option<parse_utils::LitStrOrExpr>,
     impl_for: Option<Ident>,
@@ -146,9 +153,14 @@ impl Parse for PathAttr<'_> {
                 "params" => {
                     let params;
                     parenthesized!(params in input);
-                    path_attr.params =
-                        Punctuated::<Parameter, Token![,]>::parse_terminated(&params)
-                            .map(|punctuated| punctuated.into_iter().collect::<Vec<Parameter>>())?;
+                    let mut lists = ParameterList::parse_all(&params)?;
+                    ParameterList::validate_required_groups(&lists)?;
+                    ParameterList::resolve_description_refs(&mut lists)?;
+                    path_attr.params = lists
+                        .into_iter()
+                        .flat_map(Vec::<Parameter>::from)
+                        .collect::<Vec<Parameter>>();
+                    path_attr.attr_params_len = path_attr.params.len();
                 }
                 "tag" => {
                     path_attr.tag = Some(parse_utils::parse_next_literal_str_or_expr(input)?);
@@ -291,8 +303,9 @@ pub struct Path<'p> {
     fn_ident: &'p Ident,
     ext_methods: Vec<HttpMethod>,
     path: Option<String>,
-    doc_comments: Option<Vec<String>>,
+    doc_comments: Option<CommentAttributes>,
     deprecated: bool,
+    deprecated_note: Option<String>,
 }
 
 impl<'p> Path<'p> {
@@ -304,6 +317,7 @@ impl<'p> Path<'p> {
             path: None,
             doc_comments: None,
             deprecated: false,
+            deprecated_note: None,
         }
     }
 
@@ -319,7 +333,7 @@ impl<'p> Path<'p> {
         self
     }
 
-    pub fn doc_comments(mut self, doc_comments: Vec<String>) -> Self {
+    pub fn doc_comments(mut self, doc_comments: CommentAttributes) -> Self {
         self.doc_comments = Some(doc_comments);
 
         self
@@ -330,6 +344,30 @@ impl<'p> Path<'p> {
 
         self
     }
+
+    pub fn deprecated_note(mut self, deprecated_note: Option<String>) -> Self {
+        self.deprecated_note = deprecated_note;
+
+        self
+    }
+
+    /// Get the operation path with context path if both are known at macro expansion time,
+    /// that is they are string literals or the path is resolved from framework extensions.
+    fn template_path(&self) -> Option<String> {
+        let path = match &self.path_attr.path {
+            Some(parse_utils::LitStrOrExpr::LitStr(path)) => path.value(),
+            Some(parse_utils::LitStrOrExpr::Expr(_)) => return None,
+            None => self.path.clone()?,
+        };
+
+        match &self.path_attr.context_path {
+            Some(parse_utils::LitStrOrExpr::LitStr(context_path)) => {
+                Some(format!("{}{path}", context_path.value()))
+            }
+            Some(parse_utils::LitStrOrExpr::Expr(_)) => None,
+            None => Some(path),
+        }
+    }
 }
 
 impl<'p> ToTokensDiagnostics for Path<'p> {
@@ -404,6 +442,14 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
                 diagnostics()
             })?;
 
+        if let Some(path) = self.template_path() {
+            // parameters from extensions are resolved from the handler and may have no name
+            let attr_params = &self.path_attr.params[..self.path_attr.attr_params_len];
+            for parameter in attr_params {
+                parameter.validate_against_path(&path)?;
+            }
+        }
+
         let path_with_context_path = self
             .path_attr
             .context_path
@@ -424,17 +470,10 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
                 }
             });
 
-        let split_comment = self.doc_comments.as_ref().map(|comments| {
-            let mut split = comments.split(|comment| comment.trim().is_empty());
-            let summary = split
-                .by_ref()
-                .next()
-                .map(|summary| summary.join("\n"))
-                .unwrap_or_default();
-            let description = split.map(|lines| lines.join("\n")).collect::<Vec<_>>();
-
-            (summary, description)
-        });
+        let split_comment = self
+            .doc_comments
+            .as_ref()
+            .map(CommentAttributes::as_summary_and_description);
 
         let summary = self
             .path_attr
@@ -444,7 +483,8 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             .or_else(|| {
                 split_comment
                     .as_ref()
-                    .map(|(summary, _)| Summary::Str(summary))
+                    .and_then(|(summary, _)| summary.as_deref())
+                    .map(Summary::Str)
             });
 
         let description = self
@@ -455,11 +495,12 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             .or_else(|| {
                 split_comment
                     .as_ref()
-                    .map(|(_, description)| Description::Vec(description))
+                    .map(|(_, description)| Description::Str(description))
             });
 
         let operation: Operation = Operation {
             deprecated: self.deprecated,
+            deprecated_note: self.deprecated_note.as_deref(),
             operation_id,
             summary,
             description,
@@ -621,6 +662,7 @@ struct Operation<'a> {
     summary: Option<Summary<'a>>,
     description: Option<Description<'a>>,
     deprecated: bool,
+    deprecated_note: Option<&'a str>,
     parameters: &'a Vec<Parameter<'a>>,
     request_body: Option<&'a RequestBodyAttr<'a>>,
     responses: &'a Vec<Response<'a>>,
@@ -658,6 +700,17 @@ impl ToTokensDiagnostics for Operation<'_> {
             tokens.extend(quote!( .deprecated(Some(#deprecated))))
         }
 
+        // OpenAPI does not have a field for deprecation note, thus it is added as extension
+        if let Some(note) = self.deprecated_note {
+            tokens.extend(quote! {
+                .extensions(Some(
+                    utoipa::openapi::extensions::ExtensionsBuilder::new()
+                        .add("x-deprecated-reason", #note)
+                        .build()
+                ))
+            })
+        }
+
         if let Some(summary) = &self.summary {
             summary.to_tokens(tokens);
         }
@@ -677,7 +730,7 @@ impl ToTokensDiagnostics for Operation<'_> {
 #[cfg_attr(feature = "debug", derive(Debug))]
 enum Description<'a> {
     Value(&'a parse_utils::LitStrOrExpr),
-    Vec(&'a [String]),
+    Str(&'a str),
 }
 
 impl ToTokens for Description<'_> {
@@ -686,15 +739,10 @@ impl ToTokens for Description<'_> {
             Self::Value(value) => tokens.extend(quote! {
                 .description(Some(#value))
             }),
-            Self::Vec(vec) => {
-                let description = vec.join("\n\n");
-
-                if !description.is_empty() {
-                    tokens.extend(quote! {
-                        .description(Some(#description))
-                    })
-                }
-            }
+            Self::Str(str) if !str.is_empty() => tokens.extend(quote! {
+                .description(Some(#str))
+            }),
+            _ => (),
         }
     }
 }
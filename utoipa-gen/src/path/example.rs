@@ -7,10 +7,10 @@ use syn::{parenthesized, Error, LitStr, Token};
 use crate::{parse_utils, AnyValue};
 
 // (name = (summary = "...", description = "...", value = "..", external_value = "..."))
-#[derive(Default)]
+#[derive(Default, Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Example {
-    pub(super) name: String,
+    pub(crate) name: String,
     pub(super) summary: Option<String>,
     pub(super) description: Option<String>,
     pub(super) value: Option<AnyValue>,
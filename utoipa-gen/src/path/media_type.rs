@@ -362,6 +362,7 @@ impl<'r> From<ParsedType<'r>> for Schema<'r> {
 }
 
 // inline(syn::TypePath) | syn::TypePath
+#[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ParsedType<'i> {
     pub ty: Cow<'i, Type>,
@@ -1,10 +1,14 @@
-use std::{borrow::Cow, fmt::Display};
+use std::{borrow::Cow, fmt::Display, str::FromStr};
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::{
+    ext::IdentExt,
     parenthesized,
-    parse::{Parse, ParseBuffer, ParseStream},
+    parse::{discouraged::Speculative, Parse, ParseBuffer, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Paren,
     Error, Generics, LitStr, Token, TypePath,
 };
 
@@ -14,19 +18,25 @@ use crate::{
         self,
         features::{
             attributes::{
-                AllowReserved, Description, Example, Explode, Format, Nullable, ReadOnly, Style,
-                WriteOnly, XmlAttr,
+                Alias, AllowEmptyValue, AllowReserved, Boolean, Const, ContentType, CookieHttpOnly,
+                CookieSameSite, DeprecatedSince, Description, DescriptionRef, DescriptionTemplate,
+                EnumValues, Example, ExampleUrl, Explode, Extensions, Format, NoSchema, Nullable,
+                ParameterContent, ParameterEncoding, ParameterExamples, ReadOnly, Rename,
+                SchemaRef, SchemaTypeOverride, Style, WriteOnly, XmlAttr,
             },
-            impl_into_inner, parse_features,
+            impl_into_inner, parse_features, pop_feature,
             validation::{
                 ExclusiveMaximum, ExclusiveMinimum, MaxItems, MaxLength, Maximum, MinItems,
-                MinLength, Minimum, MultipleOf, Pattern,
+                MinLength, Minimum, MultipleOf, Pattern, UniqueItems,
             },
-            Feature, ToTokensExt,
+            validators::IsNotDateFormat,
+            Feature, FeaturesExt, IntoInner, ToTokensExt, Validatable, Validate,
         },
-        ComponentSchema, Container, TypeTree,
+        ComponentSchema, Container, GenericType, TypeTree, ValueType,
     },
-    parse_utils, Diagnostics, Required, ToTokensDiagnostics,
+    parse_utils,
+    schema_type::SchemaType,
+    Diagnostics, Required, ToTokensDiagnostics,
 };
 
 use super::media_type::ParsedType;
@@ -42,12 +52,96 @@ use super::media_type::ParsedType;
 /// * ("id", path, deprecated, description = "Users database id"),
 ///
 /// The `= String` type statement is optional if automatic resolution is supported.
+///
+/// Reusable parameter from components can be referenced with `ref = "Name"` syntax.
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone)]
 pub enum Parameter<'a> {
-    Value(ValueParameter<'a>),
+    Value(Box<ValueParameter<'a>>),
     /// Identifier for a struct that implements `IntoParams` trait.
     IntoParamsIdent(IntoParamsIdentParameter<'a>),
+    /// Reference to a reusable parameter in components.
+    Ref(ParameterRef),
+}
+
+impl<'p> Parameter<'p> {
+    /// Get span of the tokens the [`Parameter`] was parsed from. This is the span of the name
+    /// literal for value parameters and references, and the span of the type path for
+    /// `IntoParams` parameters. Programmatically constructed value parameters have no source
+    /// tokens thus [`Span::call_site`] is returned for them.
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Value(parameter) => parameter.span(),
+            Self::IntoParamsIdent(parameter) => parameter.path.span(),
+            Self::Ref(parameter) => parameter.span,
+        }
+    }
+
+    /// Validate location of value [`Parameter`] against the operation `path` template. Fails if
+    /// [`ParameterIn::Path`] parameter is not found as _`{name}`_ segment of the `path` or if
+    /// parameter in other location collides with a template segment.
+    ///
+    /// Other than value parameters are always valid since their names are not known at macro
+    /// expansion time.
+    pub fn validate_against_path(&self, path: &str) -> Result<(), syn::Error> {
+        let Parameter::Value(parameter) = self else {
+            return Ok(());
+        };
+
+        let is_template_segment = Self::path_template_names(path)
+            .iter()
+            .any(|name| *name == parameter.documented_name());
+        let span = self.span();
+
+        match &parameter.parameter_in {
+            ParameterIn::Path if !is_template_segment => Err(Error::new(
+                span,
+                format!(
+                    "`Path` parameter `{name}` is not found in path `{path}`, expected path to contain `{{{name}}}`",
+                    name = parameter.documented_name()
+                ),
+            )),
+            parameter_in if *parameter_in != ParameterIn::Path && is_template_segment => {
+                Err(Error::new(
+                    span,
+                    format!(
+                        "parameter `{name}` is in `{parameter_in}` but path `{path}` has `{{{name}}}` segment, did you mean `Path`?",
+                        name = parameter.documented_name()
+                    ),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Get names of _`{name}`_ template segments of the `path`. Possible regex after `:` e.g.
+    /// _`{tail:.*}`_ is stripped and escaped braces _`{{`_ and _`}}`_ are treated as literal
+    /// braces instead of template segments.
+    fn path_template_names(path: &str) -> Vec<&str> {
+        let mut names = Vec::new();
+        let mut rest = path;
+
+        while let Some(start) = rest.find(['{', '}']) {
+            let after = &rest[start + 1..];
+            if rest[start..].starts_with("{{") || rest[start..].starts_with("}}") {
+                rest = &after[1..];
+                continue;
+            }
+            if rest[start..].starts_with('}') {
+                rest = after;
+                continue;
+            }
+
+            let Some(end) = after.find('}') else {
+                break;
+            };
+            let name = &after[..end];
+            names.push(name.split(':').next().unwrap_or(name).trim());
+            rest = &after[end + 1..];
+        }
+
+        names
+    }
 }
 
 #[cfg(any(
@@ -59,9 +153,13 @@ impl<'p> Parameter<'p> {
     pub fn merge(&mut self, other: Parameter<'p>) {
         match (self, other) {
             (Self::Value(value), Parameter::Value(other)) => {
-                let (schema_features, _) = &value.features;
-                // if value parameter schema has not been defined use the external one
-                if value.parameter_schema.is_none() {
+                let (schema_features, param_features) = &value.features;
+                let has_content = param_features
+                    .iter()
+                    .any(|feature| matches!(feature, Feature::ParameterContent(_)));
+                // if value parameter schema has not been defined use the external one unless
+                // the parameter is described with `content` media types instead
+                if value.parameter_schema.is_none() && !has_content {
                     value.parameter_schema = other.parameter_schema;
                 }
 
@@ -79,7 +177,9 @@ impl<'p> Parameter<'p> {
 
 impl Parse for Parameter<'_> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        if input.fork().parse::<TypePath>().is_ok() {
+        if input.peek(Token![ref]) {
+            Ok(Self::Ref(input.parse()?))
+        } else if input.fork().parse::<TypePath>().is_ok() {
             Ok(Self::IntoParamsIdent(IntoParamsIdentParameter {
                 path: Cow::Owned(input.parse::<TypePath>()?.path),
                 parameter_in_fn: None,
@@ -90,11 +190,253 @@ impl Parse for Parameter<'_> {
     }
 }
 
+/// List of [`Parameter`]s parsed from a single item within `params(...)`.
+///
+/// In addition to single [`Parameter`] the list supports grouped syntax where leading
+/// [`ParameterIn`] is shared by all following parameters:
+///
+/// * (Query, "page" = i32, "size" = i32)
+/// * (Query, "page", "size")
+///
+/// Grouped parameters can be marked as all-or-nothing group with `required_group = "name"`
+/// placed after the [`ParameterIn`]:
+///
+/// * (Query, required_group = "dates", "from" = String, "to" = String)
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ParameterList<'a>(pub Vec<Parameter<'a>>);
+
+impl<'a> From<ParameterList<'a>> for Vec<Parameter<'a>> {
+    fn from(value: ParameterList<'a>) -> Self {
+        value.0
+    }
+}
+
+impl ParameterList<'_> {
+    fn is_grouped(input: ParseStream) -> bool {
+        fn parse_group_parameter_in(input: ParseStream) -> syn::Result<ParameterIn> {
+            let content;
+            parenthesized!(content in input);
+            content.parse()
+        }
+
+        parse_group_parameter_in(&input.fork()).is_ok()
+    }
+
+    /// Parse optional `required_group = "name"` of grouped parameters.
+    fn parse_required_group(input: ParseStream) -> syn::Result<Option<LitStr>> {
+        if !(input.peek(syn::Ident) && input.peek2(Token![=])) {
+            return Ok(None);
+        }
+
+        let attribute = input.parse::<Ident>()?;
+        if attribute != "required_group" {
+            return Err(Error::new(
+                attribute.span(),
+                format!("unexpected attribute `{attribute}`, expected `required_group`"),
+            ));
+        }
+        let group = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+        let name = group.value();
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(Error::new(
+                group.span(),
+                r#"expected required group name without whitespace e.g. `required_group = "dates"`"#,
+            ));
+        }
+        input.parse::<Token![,]>()?;
+
+        Ok(Some(group))
+    }
+
+    /// Parse comma separated [`ParameterList`]s of `params(...)`. Unlike parsing with
+    /// [`Punctuated`] this does not stop to the first invalid parenthesized parameter but
+    /// continues to the next one and returns errors of all invalid parameters combined.
+    ///
+    /// Every iteration either consumes at least one token or stops with an error, thus parsing
+    /// terminates with any malformed input.
+    pub fn parse_all(input: ParseStream) -> syn::Result<Vec<Self>> {
+        let mut lists = Vec::new();
+        let mut errors = Vec::new();
+
+        while !input.is_empty() {
+            let fork = input.fork();
+            match fork.parse::<ParameterList>() {
+                Ok(list) if fork.cursor() != input.cursor() => {
+                    input.advance_to(&fork);
+                    lists.push(list);
+                }
+                Ok(_) => {
+                    errors.push(input.error("unexpected token, expected parameter"));
+                    break;
+                }
+                // parenthesized parameter can be skipped as a whole to parse the next one
+                Err(error) if input.peek(Paren) => {
+                    errors.push(error);
+                    input.parse::<proc_macro2::Group>()?;
+                }
+                Err(error) => {
+                    errors.push(error);
+                    break;
+                }
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            if let Err(error) = input.parse::<Token![,]>() {
+                errors.push(error);
+                break;
+            }
+        }
+
+        match parse_utils::combine_errors(errors) {
+            Some(error) => Err(error),
+            None => Ok(lists),
+        }
+    }
+
+    /// Resolve `description_ref = "name"` of parameters by replacing it with the description of
+    /// the referenced parameter within the same `params(...)`. Referenced `description_template`
+    /// is expanded with the name of the referencing parameter. Parameters are resolved in
+    /// definition order thus a parameter can reference a previously defined parameter which
+    /// itself has `description_ref`.
+    pub fn resolve_description_refs(lists: &mut [ParameterList]) -> syn::Result<()> {
+        let mut parameters = lists
+            .iter_mut()
+            .flat_map(|list| list.0.iter_mut())
+            .filter_map(|parameter| match parameter {
+                Parameter::Value(parameter) => Some(parameter),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut errors = Vec::new();
+
+        for index in 0..parameters.len() {
+            let Some(description_ref) =
+                parameters[index]
+                    .features
+                    .1
+                    .iter()
+                    .find_map(|feature| match feature {
+                        Feature::DescriptionRef(description_ref) => Some(description_ref.clone()),
+                        _ => None,
+                    })
+            else {
+                continue;
+            };
+            let name = description_ref.name();
+
+            let Some(referenced) = parameters.iter().find(|parameter| parameter.name == name)
+            else {
+                errors.push(Error::new(
+                    description_ref.span(),
+                    format!("unknown parameter `{name}` in `description_ref`, expected name of a parameter defined in the same `params(...)`"),
+                ));
+                continue;
+            };
+            let Some(description) = referenced.features.1.iter().find(|feature| {
+                matches!(
+                    feature,
+                    Feature::Description(_) | Feature::DescriptionTemplate(_)
+                )
+            }) else {
+                errors.push(Error::new(
+                    description_ref.span(),
+                    format!(
+                        "parameter `{name}` referenced by `description_ref` has no description"
+                    ),
+                ));
+                continue;
+            };
+            let description = description.clone();
+
+            for feature in &mut parameters[index].features.1 {
+                if matches!(feature, Feature::DescriptionRef(_)) {
+                    *feature = description.clone();
+                }
+            }
+        }
+
+        match parse_utils::combine_errors(errors) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Validate that every `required_group` is defined only once within the `params(...)`
+    /// because parameters of a single group must be defined in the same grouped parameter list.
+    pub fn validate_required_groups<'l>(
+        lists: impl IntoIterator<Item = &'l ParameterList<'l>>,
+    ) -> syn::Result<()> {
+        let mut groups = Vec::<String>::new();
+        for group in lists.into_iter().filter_map(|list| match list.0.first() {
+            Some(Parameter::Value(parameter)) => parameter.required_group.as_ref(),
+            _ => None,
+        }) {
+            let name = group.value();
+            if groups.contains(&name) {
+                return Err(Error::new(
+                    group.span(),
+                    format!("required group `{name}` is defined multiple times, define all parameters of the group in single grouped parameter list"),
+                ));
+            }
+            groups.push(name);
+        }
+
+        Ok(())
+    }
+}
+
+impl Parse for ParameterList<'_> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if !Self::is_grouped(input) {
+            return Ok(Self(vec![input.parse()?]));
+        }
+
+        let content: ParseBuffer;
+        parenthesized!(content in input);
+        let parameter_in = content.parse::<ParameterIn>()?;
+        content.parse::<Token![,]>()?;
+        let required_group = Self::parse_required_group(&content)?;
+        if content.is_empty() {
+            return Err(content.error(
+                r#"expected parameters after the location e.g. `(Query, "page", "size")`"#,
+            ));
+        }
+
+        let parameters = Punctuated::<ValueParameter, Token![,]>::parse_terminated_with(
+            &content,
+            ValueParameter::parse_name_and_type,
+        )?;
+
+        if let Some(group) = required_group.as_ref().filter(|_| parameters.len() < 2) {
+            return Err(Error::new(
+                group.span(),
+                format!(
+                    "required group `{}` must contain at least two parameters",
+                    group.value()
+                ),
+            ));
+        }
+
+        let parameters = parameters
+            .into_iter()
+            .map(|mut parameter| {
+                parameter.parameter_in = parameter_in.clone();
+                parameter.required_group.clone_from(&required_group);
+                Parameter::Value(Box::new(parameter))
+            })
+            .collect();
+
+        Ok(Self(parameters))
+    }
+}
+
 impl ToTokensDiagnostics for Parameter<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
         match self {
             Parameter::Value(parameter) => {
-                let parameter = as_tokens_or_diagnostics!(parameter);
+                let parameter = as_tokens_or_diagnostics!(parameter.as_ref());
                 tokens.extend(quote! { .parameter(#parameter) });
             }
             Parameter::IntoParamsIdent(IntoParamsIdentParameter {
@@ -113,6 +455,9 @@ impl ToTokensDiagnostics for Parameter<'_> {
                     )
                 })
             }
+            Parameter::Ref(ParameterRef { name, .. }) => tokens.extend(quote! {
+                .parameter(utoipa::openapi::Ref::from_parameter_name(#name))
+            }),
         }
 
         Ok(())
@@ -126,7 +471,7 @@ impl ToTokensDiagnostics for Parameter<'_> {
 ))]
 impl<'a> From<crate::ext::ValueArgument<'a>> for Parameter<'a> {
     fn from(argument: crate::ext::ValueArgument<'a>) -> Self {
-        Self::Value(ValueParameter {
+        Self::Value(Box::new(ValueParameter {
             name: argument.name.unwrap_or_else(|| Cow::Owned(String::new())),
             parameter_in: if argument.argument_in == crate::ext::ArgumentIn::Path {
                 ParameterIn::Path
@@ -138,7 +483,7 @@ impl<'a> From<crate::ext::ValueArgument<'a>> for Parameter<'a> {
                 features: Vec::new(),
             }),
             ..Default::default()
-        })
+        }))
     }
 }
 
@@ -157,17 +502,60 @@ impl<'a> From<crate::ext::IntoParamsType<'a>> for Parameter<'a> {
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
 struct ParameterSchema<'p> {
     parameter_type: ParameterType<'p>,
     features: Vec<Feature>,
 }
 
-impl ToTokensDiagnostics for ParameterSchema<'_> {
-    fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
-        let mut to_tokens = |param_schema, required| {
-            tokens.extend(quote! { .schema(Some(#param_schema)).required(#required) });
+impl ParameterSchema<'_> {
+    /// Write `schema` or `content` tokens of the parameter along with the required status to
+    /// `tokens`. Property `encoding` is only applied to `content` of parameter with
+    /// `content_type`.
+    fn to_tokens_with_encoding(
+        &self,
+        tokens: &mut TokenStream,
+        encoding: Option<&ParameterEncoding>,
+    ) -> Result<(), Diagnostics> {
+        let mut features = self.features.clone();
+        let content_type: Option<ContentType> =
+            pop_feature!(features => Feature::ContentType(_)).into_inner();
+
+        let (param_schema, required) = self.to_schema_tokens(features)?;
+        let required: Required = required.into();
+
+        match &content_type {
+            // parameter with content type is serialized as `content` instead of `schema`
+            Some(content_type) => tokens.extend(quote! {
+                .content(
+                    #content_type,
+                    utoipa::openapi::content::ContentBuilder::new()
+                        .schema(Some(#param_schema))
+                        #encoding
+                        .build()
+                )
+                .required(#required)
+            }),
+            None => tokens.extend(quote! { .schema(Some(#param_schema)).required(#required) }),
         };
 
+        Ok(())
+    }
+
+    /// Get schema tokens of the parameter type with given `features` along with whether the
+    /// parameter type is required i.e. it is not an `Option`.
+    fn to_schema_tokens(&self, features: Vec<Feature>) -> Result<(TokenStream, bool), Diagnostics> {
+        let mut features = features;
+        Self::validate_date_format_bounds(&features)?;
+        let schema_type: Option<SchemaTypeOverride> =
+            pop_feature!(features => Feature::SchemaTypeOverride(_)).into_inner();
+        if let Some(schema_type) = schema_type {
+            let is_option = self.is_option()?;
+            let schema = Self::schema_type_override_tokens(schema_type, is_option, features)?;
+
+            return Ok((schema, !is_option));
+        }
+
         match &self.parameter_type {
             #[cfg(any(
                 feature = "actix_extras",
@@ -175,48 +563,377 @@ impl ToTokensDiagnostics for ParameterSchema<'_> {
                 feature = "axum_extras"
             ))]
             ParameterType::External(type_tree) => {
-                let required: Required = (!type_tree.is_option()).into();
-
-                to_tokens(
-                    ComponentSchema::new(component::ComponentSchemaProps {
-                        type_tree,
-                        features: self.features.clone(),
-                        description: None,
-                        container: &Container {
-                            generics: &Generics::default(),
-                        },
-                    })?
-                    .to_token_stream(),
-                    required,
-                );
-                Ok(())
+                Self::validate_schema_features(&features, type_tree)?;
+                let schema = ComponentSchema::new(component::ComponentSchemaProps {
+                    type_tree,
+                    features,
+                    description: None,
+                    container: &Container {
+                        generics: &Generics::default(),
+                    },
+                })?
+                .to_token_stream();
+
+                Ok((schema, !type_tree.is_option()))
             }
             ParameterType::Parsed(inline_type) => {
                 let type_tree = TypeTree::from_type(inline_type.ty.as_ref())?;
-                let required: Required = (!type_tree.is_option()).into();
-                let mut schema_features = Vec::<Feature>::new();
-                schema_features.clone_from(&self.features);
+                if let Some(Feature::Format(format)) = features
+                    .iter()
+                    .find(|feature| matches!(feature, Feature::Format(_)))
+                {
+                    Self::validate_format(format, &type_tree)?;
+                }
+                if let Some(Feature::EnumValues(enum_values)) = features
+                    .iter()
+                    .find(|feature| matches!(feature, Feature::EnumValues(_)))
+                {
+                    Self::validate_enum_values(enum_values, &type_tree)?;
+                }
+                Self::validate_schema_features(&features, &type_tree)?;
+                let mut schema_features = features;
                 schema_features.push(Feature::Inline(inline_type.is_inline.into()));
 
-                to_tokens(
-                    ComponentSchema::new(component::ComponentSchemaProps {
-                        type_tree: &type_tree,
-                        features: schema_features,
-                        description: None,
-                        container: &Container {
-                            generics: &Generics::default(),
-                        },
-                    })?
-                    .to_token_stream(),
-                    required,
-                );
-                Ok(())
+                let schema = ComponentSchema::new(component::ComponentSchemaProps {
+                    type_tree: &type_tree,
+                    features: schema_features,
+                    description: None,
+                    container: &Container {
+                        generics: &Generics::default(),
+                    },
+                })?
+                .to_token_stream();
+
+                Ok((schema, !type_tree.is_option()))
+            }
+        }
+    }
+
+    /// Validate that `format` belongs to the same type family as the parameter type, e.g.
+    /// `int64` is only allowed for numbers and `date-time` only for strings. Formats unknown
+    /// to OpenAPI and types that are not primitives, e.g. collections, are not checked.
+    fn validate_format(format: &Format, type_tree: &TypeTree) -> Result<(), Diagnostics> {
+        let type_tree = Self::unwrap_wrapper_types(type_tree);
+        let Some(path) = type_tree
+            .path
+            .as_deref()
+            .filter(|_| type_tree.value_type == ValueType::Primitive)
+        else {
+            return Ok(());
+        };
+        let schema_type = SchemaType {
+            path: Cow::Borrowed(path),
+            nullable: false,
+        };
+
+        let known_format = format.known_format();
+        if known_format.is_number_format() && !schema_type.is_number() {
+            Err(Diagnostics::with_span(
+                format.span(),
+                "`format` error: can only be used with `number` type",
+            ))
+        } else if known_format.is_string_format() && !schema_type.is_string() {
+            Err(Diagnostics::with_span(
+                format.span(),
+                "`format` error: can only be used with `string` type",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validate that `enum_values` are compatible with the parameter type, e.g. integer type only
+    /// allows integer values. For collections the values are validated against the item type.
+    /// Types that are not primitives are not checked.
+    fn validate_enum_values(
+        enum_values: &EnumValues,
+        type_tree: &TypeTree,
+    ) -> Result<(), Diagnostics> {
+        let mut type_tree = Self::unwrap_wrapper_types(type_tree);
+        if let (Some(GenericType::Vec | GenericType::LinkedList | GenericType::Set), Some([item])) =
+            (type_tree.generic_type, type_tree.children.as_deref())
+        {
+            type_tree = Self::unwrap_wrapper_types(item);
+        }
+        let Some(path) = type_tree
+            .path
+            .as_deref()
+            .filter(|_| type_tree.value_type == ValueType::Primitive)
+        else {
+            return Ok(());
+        };
+
+        enum_values.validate_type(&SchemaType {
+            path: Cow::Borrowed(path),
+            nullable: false,
+        })
+    }
+
+    /// Validate that validation attributes e.g. `minimum` are applicable to the parameter type.
+    /// Array attributes e.g. `max_items` are validated against collections and other attributes
+    /// against the item type of collections. Types that are not primitives are not checked.
+    fn validate_schema_features<'f>(
+        features: impl IntoIterator<Item = &'f Feature>,
+        type_tree: &TypeTree,
+    ) -> Result<(), Diagnostics> {
+        let type_tree = Self::unwrap_wrapper_types(type_tree);
+        let features = features
+            .into_iter()
+            .filter(|feature| feature.is_validatable());
+        let validate = |feature: &Feature| {
+            let path = type_tree.path.as_deref()?;
+            feature.validate(
+                &SchemaType {
+                    path: Cow::Borrowed(path),
+                    nullable: false,
+                },
+                type_tree,
+            )
+        };
+
+        if let (Some(GenericType::Vec | GenericType::LinkedList | GenericType::Set), Some([item])) =
+            (type_tree.generic_type, type_tree.children.as_deref())
+        {
+            let (array_features, item_features): (Vec<_>, Vec<_>) = features.partition(|feature| {
+                matches!(
+                    feature,
+                    Feature::MaxItems(_) | Feature::MinItems(_) | Feature::UniqueItems(_)
+                )
+            });
+            if let Some(diagnostics) = array_features.into_iter().find_map(validate) {
+                return Err(diagnostics);
+            }
+
+            return Self::validate_schema_features(item_features, item);
+        }
+
+        if type_tree.value_type != ValueType::Primitive {
+            return Ok(());
+        }
+        features.filter_map(validate).next().map_or(Ok(()), Err)
+    }
+
+    /// Validate that numeric bounds are not used together with `date` or `date-time` format.
+    fn validate_date_format_bounds(features: &[Feature]) -> Result<(), Diagnostics> {
+        let Some(format) = features.iter().find_map(|feature| match feature {
+            Feature::Format(format) => Some(format.known_format()),
+            _ => None,
+        }) else {
+            return Ok(());
+        };
+
+        features
+            .iter()
+            .find_map(|feature| match feature {
+                Feature::Minimum(minimum) => minimum.validate(IsNotDateFormat(format)),
+                Feature::Maximum(maximum) => maximum.validate(IsNotDateFormat(format)),
+                Feature::ExclusiveMinimum(exclusive_minimum) => {
+                    exclusive_minimum.validate(IsNotDateFormat(format))
+                }
+                Feature::ExclusiveMaximum(exclusive_maximum) => {
+                    exclusive_maximum.validate(IsNotDateFormat(format))
+                }
+                _ => None,
+            })
+            .map_or(Ok(()), Err)
+    }
+
+    /// Check whether the parameter type is serialized as an `object`, that is it is a map or
+    /// other non-primitive type e.g. a `ToSchema` struct.
+    fn is_object(&self) -> Result<bool, Diagnostics> {
+        let is_object = |type_tree: &TypeTree| {
+            let type_tree = Self::unwrap_wrapper_types(type_tree);
+            match type_tree.generic_type {
+                Some(generic_type) => generic_type == GenericType::Map,
+                None => matches!(type_tree.value_type, ValueType::Object | ValueType::Value),
+            }
+        };
+
+        match &self.parameter_type {
+            #[cfg(any(
+                feature = "actix_extras",
+                feature = "rocket_extras",
+                feature = "axum_extras"
+            ))]
+            ParameterType::External(type_tree) => Ok(is_object(type_tree)),
+            ParameterType::Parsed(inline_type) => {
+                TypeTree::from_type(inline_type.ty.as_ref()).map(|type_tree| is_object(&type_tree))
+            }
+        }
+    }
+
+    /// Check whether the parameter type is serialized as an `array` e.g. `Vec<T>`.
+    fn is_array(&self) -> Result<bool, Diagnostics> {
+        let is_array = |type_tree: &TypeTree| {
+            matches!(
+                Self::unwrap_wrapper_types(type_tree).generic_type,
+                Some(GenericType::Vec | GenericType::LinkedList | GenericType::Set)
+            )
+        };
+
+        match &self.parameter_type {
+            #[cfg(any(
+                feature = "actix_extras",
+                feature = "rocket_extras",
+                feature = "axum_extras"
+            ))]
+            ParameterType::External(type_tree) => Ok(is_array(type_tree)),
+            ParameterType::Parsed(inline_type) => {
+                TypeTree::from_type(inline_type.ty.as_ref()).map(|type_tree| is_array(&type_tree))
+            }
+        }
+    }
+
+    /// Get schema tokens where the schema `type` is forced by `schema_type` override instead of
+    /// resolving it from the parameter type. The schema is nullable if the parameter type is an
+    /// `Option` unless explicitly defined otherwise with `nullable`.
+    fn schema_type_override_tokens(
+        schema_type: SchemaTypeOverride,
+        is_option: bool,
+        mut features: Vec<Feature>,
+    ) -> Result<TokenStream, Diagnostics> {
+        let nullable: Option<Nullable> =
+            pop_feature!(features => Feature::Nullable(_)).into_inner();
+        let schema_type = schema_type.schema_type();
+        let schema_type = if nullable.map_or(is_option, |nullable| nullable.value()) {
+            quote! {
+                {
+                    use std::iter::FromIterator;
+                    utoipa::openapi::schema::SchemaType::from_iter([
+                        #schema_type,
+                        utoipa::openapi::schema::Type::Null
+                    ])
+                }
+            }
+        } else {
+            quote! { utoipa::openapi::schema::SchemaType::new(#schema_type) }
+        };
+        let features = features.to_token_stream()?;
+
+        Ok(quote! {
+            utoipa::openapi::ObjectBuilder::new()
+                .schema_type(#schema_type)
+                #features
+        })
+    }
+
+    /// Get tokens resolving the deprecation of the parameter from the schema of the parameter
+    /// type. Tokens are only created for component types e.g. `ToSchema` structs since other types
+    /// cannot be deprecated.
+    fn deprecated_tokens(&self) -> Option<TokenStream> {
+        let deprecated = |type_tree: &TypeTree| {
+            let type_tree = Self::unwrap_wrapper_types(type_tree);
+            if type_tree.generic_type.is_some() || type_tree.value_type != ValueType::Object {
+                return None;
+            }
+            let path = type_tree.path.as_ref()?;
+
+            Some(quote! {
+                match <#path as utoipa::PartialSchema>::schema() {
+                    utoipa::openapi::RefOr::T(schema) if schema.is_deprecated() => {
+                        Some(utoipa::openapi::Deprecated::True)
+                    }
+                    _ => None,
+                }
+            })
+        };
+
+        match &self.parameter_type {
+            #[cfg(any(
+                feature = "actix_extras",
+                feature = "rocket_extras",
+                feature = "axum_extras"
+            ))]
+            ParameterType::External(type_tree) => deprecated(type_tree),
+            ParameterType::Parsed(inline_type) => TypeTree::from_type(inline_type.ty.as_ref())
+                .ok()
+                .and_then(|type_tree| deprecated(&type_tree)),
+        }
+    }
+
+    fn is_option(&self) -> Result<bool, Diagnostics> {
+        match &self.parameter_type {
+            #[cfg(any(
+                feature = "actix_extras",
+                feature = "rocket_extras",
+                feature = "axum_extras"
+            ))]
+            ParameterType::External(type_tree) => Ok(type_tree.is_option()),
+            ParameterType::Parsed(inline_type) => {
+                TypeTree::from_type(inline_type.ty.as_ref()).map(|type_tree| type_tree.is_option())
             }
         }
     }
+
+    /// Validate that type of `Path` or `Query` parameter can be serialized to the request.
+    fn validate_serializable_type(
+        &self,
+        parameter: &ValueParameter,
+        has_style: bool,
+    ) -> syn::Result<()> {
+        let inline_type = match &self.parameter_type {
+            #[cfg(any(
+                feature = "actix_extras",
+                feature = "rocket_extras",
+                feature = "axum_extras"
+            ))]
+            ParameterType::External(_) => return Ok(()),
+            ParameterType::Parsed(inline_type) => inline_type,
+        };
+        if !matches!(
+            parameter.parameter_in,
+            ParameterIn::Path | ParameterIn::Query
+        ) {
+            return Ok(());
+        }
+        // unresolvable types are reported when the schema is created
+        let Ok(type_tree) = TypeTree::from_type(inline_type.ty.as_ref()) else {
+            return Ok(());
+        };
+
+        let is_map = |type_tree: &TypeTree| {
+            Self::unwrap_wrapper_types(type_tree).generic_type == Some(GenericType::Map)
+        };
+        let type_tree = Self::unwrap_wrapper_types(&type_tree);
+        let is_map_collection = matches!(
+            type_tree.generic_type,
+            Some(GenericType::Vec | GenericType::LinkedList | GenericType::Set)
+        ) && type_tree
+            .children
+            .as_deref()
+            .is_some_and(|children| children.iter().any(is_map));
+
+        // maps are only supported with explicitly defined `style` and collections of maps are
+        // not supported at all
+        if is_map_collection || (is_map(type_tree) && !has_style) {
+            return Err(Error::new(
+                inline_type.ty.span(),
+                format!(
+                    "unsupported type for `{}` parameter `{}`, `Path` and `Query` parameters must be primitive, array of primitives or object with supported serialization `style` e.g. `style = DeepObject`",
+                    parameter.parameter_in, parameter.name
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Unwrap types which do not affect the schema type e.g. `Option<T>` and `Box<T>`.
+    fn unwrap_wrapper_types<'t>(mut type_tree: &'t TypeTree<'t>) -> &'t TypeTree<'t> {
+        while let (
+            Some(GenericType::Option | GenericType::Box | GenericType::Cow | GenericType::RefCell),
+            Some([child]),
+        ) = (type_tree.generic_type, type_tree.children.as_deref())
+        {
+            type_tree = child;
+        }
+
+        type_tree
+    }
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
 enum ParameterType<'p> {
     #[cfg(any(
         feature = "actix_extras",
@@ -227,13 +944,17 @@ enum ParameterType<'p> {
     Parsed(ParsedType<'p>),
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ValueParameter<'a> {
     pub name: Cow<'a, str>,
+    /// Span of the parameter name if parsed from `params(...)`.
+    name_span: Option<Span>,
     parameter_in: ParameterIn,
     parameter_schema: Option<ParameterSchema<'a>>,
     features: (Vec<Feature>, Vec<Feature>),
+    /// Name of the all-or-nothing group of grouped parameters.
+    required_group: Option<LitStr>,
 }
 
 impl PartialEq for ValueParameter<'_> {
@@ -244,21 +965,33 @@ impl PartialEq for ValueParameter<'_> {
 
 impl Eq for ValueParameter<'_> {}
 
-impl Parse for ValueParameter<'_> {
-    fn parse(input_with_parens: ParseStream) -> syn::Result<Self> {
-        let input: ParseBuffer;
-        parenthesized!(input in input_with_parens);
+impl ValueParameter<'_> {
+    /// Name of the parameter in OpenAPI document. This is the _`rename`_ attribute if defined,
+    /// otherwise the parameter name which is used to match the parameter with handler arguments.
+    fn documented_name(&self) -> &str {
+        let (_, param_features) = &self.features;
+        param_features
+            .iter()
+            .find_map(|feature| match feature {
+                Feature::Rename(rename) => Some(rename.as_str()),
+                _ => None,
+            })
+            .unwrap_or(&self.name)
+    }
 
+    /// Parse parameter name and optional parameter type in format `"name" = Type` or `"name"`.
+    fn parse_name_and_type(input: ParseStream) -> syn::Result<Self> {
         let mut parameter = ValueParameter::default();
 
         if input.peek(LitStr) {
             // parse name
-            let name = input.parse::<LitStr>()?.value();
-            parameter.name = Cow::Owned(name);
+            let name = input.parse::<LitStr>()?;
+            parameter.name = Cow::Owned(name.value());
+            parameter.name_span = Some(name.span());
 
             if input.peek(Token![=]) {
                 parameter.parameter_schema = Some(ParameterSchema {
-                    parameter_type: ParameterType::Parsed(parse_utils::parse_next(&input, || {
+                    parameter_type: ParameterType::Parsed(parse_utils::parse_next(input, || {
                         input.parse().map_err(|error| {
                             Error::new(
                                 error.span(),
@@ -270,45 +1003,487 @@ impl Parse for ValueParameter<'_> {
                 });
             }
         } else {
-            return Err(input.error("unparsable parameter name, expected literal string"));
+            return Err(parse_utils::unexpected_value_error(
+                input,
+                "unparsable parameter name, expected literal string",
+            ));
         }
 
-        input.parse::<Token![,]>()?;
+        Ok(parameter)
+    }
 
-        if input.fork().parse::<ParameterIn>().is_ok() {
-            parameter.parameter_in = input.parse()?;
-            if !input.is_empty() {
-                input.parse::<Token![,]>()?;
-            }
+    /// Get `style` and `explode` features of array `Cookie` parameter which are not defined in
+    /// `features`. Serialization of cookie arrays is explicitly defined as comma separated single
+    /// value since exploded values cannot be represented in a single cookie.
+    fn cookie_array_features(&self, features: &[Feature]) -> Result<Vec<Feature>, Diagnostics> {
+        let is_cookie_array = self.parameter_in == ParameterIn::Cookie
+            && self
+                .parameter_schema
+                .as_ref()
+                .map_or(Ok(false), ParameterSchema::is_array)?;
+        if !is_cookie_array {
+            return Ok(Vec::new());
         }
 
-        let (schema_features, parameter_features) = input
-            .parse::<ParameterFeatures>()?
-            .split_for_parameter_type();
+        let mut cookie_array_features = Vec::new();
+        if !features
+            .iter()
+            .any(|feature| matches!(feature, Feature::Style(_)))
+        {
+            cookie_array_features.push(Feature::Style(self.parameter_in.default_style().into()));
+        }
+        if !features
+            .iter()
+            .any(|feature| matches!(feature, Feature::Explode(_)))
+        {
+            cookie_array_features.push(Feature::Explode(false.into()));
+        }
 
-        parameter.features = (schema_features.clone(), parameter_features);
-        if let Some(parameter_schema) = &mut parameter.parameter_schema {
-            parameter_schema.features = schema_features;
+        Ok(cookie_array_features)
+    }
+
+    /// Get emission order of parameter [`Feature`] which is emitted as builder call of the
+    /// parameter. Features not listed are emitted last in the order they were defined.
+    fn feature_order(feature: &Feature) -> u8 {
+        match feature {
+            Feature::Description(_) => 0,
+            Feature::Deprecated(_) => 1,
+            Feature::Style(_) => 2,
+            Feature::Explode(_) => 3,
+            Feature::AllowReserved(_) => 4,
+            Feature::AllowEmptyValue(_) => 5,
+            Feature::Example(_) => 6,
+            Feature::ParameterExamples(_) => 7,
+            _ => u8::MAX,
         }
+    }
 
-        Ok(parameter)
+    fn span(&self) -> Span {
+        self.name_span.unwrap_or_else(Span::call_site)
     }
-}
 
-#[derive(Default)]
-#[cfg_attr(feature = "debug", derive(Debug))]
-struct ParameterFeatures(Vec<Feature>);
+    /// Attributes are snake_case thus capitalized identifier is expected to be [`ParameterIn`].
+    fn is_parameter_in_ident(ident: &Ident) -> bool {
+        ident
+            .to_string()
+            .starts_with(|c: char| c.is_ascii_uppercase())
+    }
 
-impl Parse for ParameterFeatures {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self(parse_features!(
-            // param features
-            input as Style,
-            Explode,
-            AllowReserved,
-            Example,
-            crate::component::features::attributes::Deprecated,
+    /// Check that single valued attributes are not defined multiple times. Only attribute names
+    /// are checked here, the actual attributes are parsed with [`ParameterFeatures`].
+    fn validate_unique_attributes(input: ParseStream, has_parameter_in: bool) -> syn::Result<()> {
+        const MULTI_VALUED_ATTRIBUTES: [&str; 1] = ["alias"];
+
+        let input = input.fork();
+        let mut attributes = Vec::<String>::new();
+        if has_parameter_in {
+            attributes.push(String::from("in"));
+        }
+
+        while !input.is_empty() {
+            // parse any ident to include keywords e.g. `const`
+            let is_attribute = input.peek(Ident::peek_any)
+                && (input.peek2(Token![=])
+                    || input.peek2(Token![,])
+                    || input.peek2(syn::token::Paren)
+                    || input
+                        .cursor()
+                        .token_tree()
+                        .is_some_and(|(_, next)| next.eof()));
+
+            if is_attribute {
+                let ident = input.call(Ident::parse_any)?;
+                let name = if Self::is_parameter_in_ident(&ident) {
+                    String::from("in")
+                } else {
+                    ident.to_string()
+                };
+
+                if !MULTI_VALUED_ATTRIBUTES.contains(&&*name) {
+                    if attributes.contains(&name) {
+                        return Err(Error::new(
+                            ident.span(),
+                            format!("`{name}` is already specified"),
+                        ));
+                    }
+                    attributes.push(name);
+                }
+            }
+
+            // skip attribute value until next attribute
+            while !input.is_empty() && !input.peek(Token![,]) {
+                input.parse::<proc_macro2::TokenTree>()?;
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate the combination of the parsed features of the parameter. All found errors are
+    /// returned instead of only the first one so that they can be reported at once.
+    fn validate_features(
+        parameter: &ValueParameter,
+        schema_features: &[Feature],
+        parameter_features: &[Feature],
+    ) -> Vec<Error> {
+        let mut errors = Vec::new();
+
+        if parameter.parameter_in != ParameterIn::Query {
+            if let Some(Feature::AllowReserved(allow_reserved)) = parameter_features
+                .iter()
+                .find(|feature| matches!(feature, Feature::AllowReserved(_)))
+            {
+                errors.push(Error::new(
+                    allow_reserved.get_attribute().span(),
+                    format!(
+                        "`allow_reserved` is only applicable to `Query` parameters, but parameter `{}` is in `{}`",
+                        parameter.name, parameter.parameter_in
+                    ),
+                ));
+            }
+
+            if let Some(Feature::AllowEmptyValue(allow_empty_value)) = parameter_features
+                .iter()
+                .find(|feature| matches!(feature, Feature::AllowEmptyValue(_)))
+            {
+                errors.push(Error::new(
+                    allow_empty_value.get_attribute().span(),
+                    format!(
+                        "`allow_empty_value` is only applicable to `Query` parameters, but parameter `{}` is in `{}`",
+                        parameter.name, parameter.parameter_in
+                    ),
+                ));
+            }
+        }
+
+        if parameter.parameter_in != ParameterIn::Cookie {
+            for (attribute, name) in parameter_features
+                .iter()
+                .filter_map(|feature| match feature {
+                    Feature::CookieSameSite(same_site) => {
+                        Some((same_site.get_attribute(), "x_samesite"))
+                    }
+                    Feature::CookieHttpOnly(http_only) => {
+                        Some((http_only.get_attribute(), "x_http_only"))
+                    }
+                    _ => None,
+                })
+            {
+                errors.push(Error::new(
+                    attribute.span(),
+                    format!(
+                        "`{name}` is only applicable to `Cookie` parameters, but parameter `{}` is in `{}`",
+                        parameter.name, parameter.parameter_in
+                    ),
+                ));
+            }
+        }
+
+        if parameter.parameter_in != ParameterIn::Path {
+            if let Some(Feature::Style(style)) = parameter_features
+                .iter()
+                .find(|feature| matches!(feature, Feature::Style(_)))
+            {
+                if style.get_style().is_path_only() {
+                    errors.push(Error::new(
+                        style.span(),
+                        format!(
+                            "`Matrix` and `Label` styles are only applicable to `Path` parameters, but parameter `{}` is in `{}`",
+                            parameter.name, parameter.parameter_in
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if !matches!(
+            parameter.parameter_in,
+            ParameterIn::Query | ParameterIn::Cookie
+        ) {
+            if let Some(Feature::Style(style)) = parameter_features
+                .iter()
+                .find(|feature| matches!(feature, Feature::Style(_)))
+            {
+                if style.get_style().is_query_or_cookie_only() {
+                    errors.push(Error::new(
+                    style.span(),
+                    format!(
+                        "`Form` style is only applicable to `Query` and `Cookie` parameters, but parameter `{}` is in `{}`",
+                        parameter.name, parameter.parameter_in
+                    ),
+                ));
+                }
+            }
+        }
+
+        if parameter.parameter_in != ParameterIn::Query {
+            if let Some(Feature::Style(style)) = parameter_features
+                .iter()
+                .find(|feature| matches!(feature, Feature::Style(_)))
+            {
+                if style.get_style().is_query_only() {
+                    errors.push(Error::new(
+                        style.span(),
+                        format!(
+                            "`SpaceDelimited`, `PipeDelimited` and `DeepObject` styles are only applicable to `Query` parameters, but parameter `{}` is in `{}`",
+                            parameter.name, parameter.parameter_in
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let Some(Feature::ParameterExamples(examples)) = parameter_features
+            .iter()
+            .find(|feature| matches!(feature, Feature::ParameterExamples(_)))
+        {
+            if parameter_features
+                .iter()
+                .any(|feature| matches!(feature, Feature::Example(_)))
+            {
+                errors.push(Error::new(
+                    examples.span(),
+                    "`example` and `examples` are mutually exclusive, define only one of them",
+                ));
+            }
+        }
+
+        if let Some(Feature::SchemaTypeOverride(schema_type)) = schema_features
+            .iter()
+            .filter(|feature| matches!(feature, Feature::SchemaTypeOverride(_)))
+            .nth(1)
+        {
+            errors.push(Error::new(
+                schema_type.span(),
+                "`boolean` and `schema_type` are mutually exclusive, define only one of them",
+            ));
+        }
+
+        // the error is reported at the attribute defined last
+        let read_only =
+            schema_features
+                .iter()
+                .enumerate()
+                .find_map(|(index, feature)| match feature {
+                    Feature::ReadOnly(read_only) if read_only.is_true() => {
+                        Some((index, read_only.span()))
+                    }
+                    _ => None,
+                });
+        let write_only = schema_features
+            .iter()
+            .enumerate()
+            .find_map(|(index, feature)| match feature {
+                Feature::WriteOnly(write_only) if write_only.is_true() => {
+                    Some((index, write_only.span()))
+                }
+                _ => None,
+            });
+        if let (Some(read_only), Some(write_only)) = (read_only, write_only) {
+            let (_, span) = std::cmp::max_by_key(read_only, write_only, |(index, _)| *index);
+            errors.push(Error::new(
+                span,
+                "`read_only` and `write_only` are mutually exclusive, define only one of them",
+            ));
+        }
+
+        if let Some(Feature::Default(default)) = schema_features
+            .iter()
+            .find(|feature| matches!(feature, Feature::Default(default) if default.0.is_none()))
+        {
+            errors.push(Error::new(
+                default.span(),
+                "`default` requires a value for parameters, e.g. `default = 20`",
+            ));
+        }
+
+        if let Some(Feature::ParameterContent(content)) = parameter_features
+            .iter()
+            .find(|feature| matches!(feature, Feature::ParameterContent(_)))
+        {
+            if parameter.parameter_schema.is_some() || !schema_features.is_empty() {
+                errors.push(Error::new(
+                    content.span(),
+                    "`content` cannot be used with parameter type or schema attributes, define the type of the media type in `content` instead",
+                ));
+            }
+        }
+
+        if let Some(Feature::ParameterEncoding(encoding)) = parameter_features
+            .iter()
+            .find(|feature| matches!(feature, Feature::ParameterEncoding(_)))
+        {
+            let has_content = parameter_features
+                .iter()
+                .any(|feature| matches!(feature, Feature::ParameterContent(_)))
+                || schema_features
+                    .iter()
+                    .any(|feature| matches!(feature, Feature::ContentType(_)));
+            if !has_content {
+                errors.push(Error::new(
+                    encoding.span(),
+                    "`encoding` can only be used together with `content_type` or `content`",
+                ));
+            }
+        }
+
+        if let Some(Feature::SchemaRef(schema_ref)) = parameter_features
+            .iter()
+            .find(|feature| matches!(feature, Feature::SchemaRef(_)))
+        {
+            let is_inline = matches!(
+                &parameter.parameter_schema,
+                Some(ParameterSchema { parameter_type: ParameterType::Parsed(inline_type), .. })
+                    if inline_type.is_inline
+            );
+            let has_content = parameter_features
+                .iter()
+                .any(|feature| matches!(feature, Feature::ParameterContent(_)));
+            if is_inline || has_content || !schema_features.is_empty() {
+                errors.push(Error::new(
+                    schema_ref.span(),
+                    "`schema_ref` cannot be used with `inline(...)` type, `content` or schema attributes",
+                ));
+            }
+        }
+
+        if let Some(Feature::DescriptionTemplate(template)) = parameter_features
+            .iter()
+            .find(|feature| matches!(feature, Feature::DescriptionTemplate(_)))
+        {
+            if parameter_features
+                .iter()
+                .any(|feature| matches!(feature, Feature::Description(_)))
+            {
+                errors.push(Error::new(
+                    template.span(),
+                    "`description_template` cannot be used together with `description`",
+                ));
+            }
+        }
+
+        if let Some(Feature::DescriptionRef(description_ref)) = parameter_features
+            .iter()
+            .find(|feature| matches!(feature, Feature::DescriptionRef(_)))
+        {
+            if parameter_features.iter().any(|feature| {
+                matches!(
+                    feature,
+                    Feature::Description(_) | Feature::DescriptionTemplate(_)
+                )
+            }) {
+                errors.push(Error::new(
+                    description_ref.span(),
+                    "`description_ref` cannot be used together with `description` or `description_template`",
+                ));
+            }
+        }
+
+        if let Some(Feature::DeprecatedSince(since)) = parameter_features
+            .iter()
+            .find(|feature| matches!(feature, Feature::DeprecatedSince(_)))
+        {
+            let deprecated = parameter_features.iter().any(
+                |feature| matches!(feature, Feature::Deprecated(deprecated) if deprecated.is_true()),
+            );
+            if !deprecated {
+                errors.push(Error::new(
+                    since.span(),
+                    "`since` can only be used with deprecated parameter, e.g. `deprecated, since = \"2.3.0\"`",
+                ));
+            }
+        }
+
+        let has_schema_type = schema_features
+            .iter()
+            .any(|feature| matches!(feature, Feature::SchemaTypeOverride(_)));
+        let has_style = parameter_features
+            .iter()
+            .any(|feature| matches!(feature, Feature::Style(_)));
+        if let (Some(parameter_schema), false) = (&parameter.parameter_schema, has_schema_type) {
+            if let Err(error) = parameter_schema.validate_serializable_type(parameter, has_style) {
+                errors.push(error);
+            }
+        }
+
+        errors
+    }
+}
+
+impl Parse for ValueParameter<'_> {
+    fn parse(input_with_parens: ParseStream) -> syn::Result<Self> {
+        let input: ParseBuffer;
+        parenthesized!(input in input_with_parens);
+
+        let mut parameter = ValueParameter::parse_name_and_type(&input)?;
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        let has_parameter_in = if input
+            .fork()
+            .parse::<Ident>()
+            .is_ok_and(|ident| Self::is_parameter_in_ident(&ident))
+        {
+            parameter.parameter_in = ParameterIn::from_attr_ident(&input.parse::<Ident>()?)?;
+            true
+        } else if input.peek(Token![in]) {
+            input.parse::<Token![in]>()?;
+            let location =
+                parse_utils::parse_next(&input, || parse_utils::parse_literal_str(&input))?;
+            parameter.parameter_in = ParameterIn::from_location(&location)?;
+            true
+        } else {
+            false
+        };
+        if has_parameter_in && !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        Self::validate_unique_attributes(&input, has_parameter_in)?;
+
+        let (schema_features, parameter_features) = input
+            .parse::<ParameterFeatures>()?
+            .split_for_parameter_type();
+
+        let errors = Self::validate_features(&parameter, &schema_features, &parameter_features);
+        if let Some(error) = parse_utils::combine_errors(errors) {
+            return Err(error);
+        }
+
+        parameter.features = (schema_features.clone(), parameter_features);
+        if let Some(parameter_schema) = &mut parameter.parameter_schema {
+            parameter_schema.features = schema_features;
+        }
+
+        Ok(parameter)
+    }
+}
+
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct ParameterFeatures(Vec<Feature>);
+
+impl Parse for ParameterFeatures {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self(parse_features!(
+            // param features
+            input as Style,
+            Explode,
+            AllowReserved,
+            AllowEmptyValue,
+            CookieSameSite,
+            CookieHttpOnly,
+            Example,
+            ExampleUrl,
+            ParameterExamples,
+            crate::component::features::attributes::Deprecated,
             Description,
+            crate::component::features::attributes::Required,
             // param schema features
             Format,
             WriteOnly,
@@ -324,7 +1499,24 @@ impl Parse for ParameterFeatures {
             MinLength,
             Pattern,
             MaxItems,
-            MinItems
+            MinItems,
+            UniqueItems,
+            EnumValues,
+            crate::component::features::attributes::Default,
+            Const,
+            ContentType,
+            SchemaTypeOverride,
+            Boolean,
+            Alias,
+            NoSchema,
+            Extensions,
+            Rename,
+            ParameterContent,
+            ParameterEncoding,
+            SchemaRef,
+            DeprecatedSince,
+            DescriptionTemplate,
+            DescriptionRef
         )))
     }
 }
@@ -353,7 +1545,13 @@ impl ParameterFeatures {
                     | Feature::MinLength(_)
                     | Feature::Pattern(_)
                     | Feature::MaxItems(_)
-                    | Feature::MinItems(_) => {
+                    | Feature::MinItems(_)
+                    | Feature::UniqueItems(_)
+                    | Feature::EnumValues(_)
+                    | Feature::Default(_)
+                    | Feature::Const(_)
+                    | Feature::ContentType(_)
+                    | Feature::SchemaTypeOverride(_) => {
                         schema_features.push(feature);
                     }
                     _ => {
@@ -369,9 +1567,20 @@ impl ParameterFeatures {
 
 impl_into_inner!(ParameterFeatures);
 
+/// Parameter is emitted in deterministic order regardless of the order attributes are defined
+/// in `params(...)`:
+///
+/// 1. `name` and `in`.
+/// 2. Parameter attributes ordered by [`ValueParameter::feature_order`] followed by deprecation
+///    resolved from the parameter type if `deprecated` is not defined.
+/// 3. Extensions e.g. `x-deprecated-reason` followed by custom `extensions(...)`.
+/// 4. `content` or `schema`.
+/// 5. Explicitly defined `required`.
+///
+/// New attributes must be given a place in the order to keep the generated code stable.
 impl ToTokensDiagnostics for ValueParameter<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
-        let name = &*self.name;
+        let name = self.documented_name();
         tokens.extend(quote! {
             utoipa::openapi::path::ParameterBuilder::from(utoipa::openapi::path::Parameter::new(#name))
         });
@@ -379,18 +1588,187 @@ impl ToTokensDiagnostics for ValueParameter<'_> {
         tokens.extend(quote! { .parameter_in(#parameter_in) });
 
         let (schema_features, param_features) = &self.features;
+        let mut param_features = param_features.clone();
+        let required: Option<crate::component::features::attributes::Required> =
+            pop_feature!(param_features => Feature::Required(_)).into_inner();
+        let no_schema: Option<NoSchema> =
+            pop_feature!(param_features => Feature::NoSchema(_)).into_inner();
+        let _ = pop_feature!(param_features => Feature::Rename(_));
+        let same_site: Option<CookieSameSite> =
+            pop_feature!(param_features => Feature::CookieSameSite(_)).into_inner();
+        let http_only: Option<CookieHttpOnly> =
+            pop_feature!(param_features => Feature::CookieHttpOnly(_)).into_inner();
+        let content: Option<ParameterContent> =
+            pop_feature!(param_features => Feature::ParameterContent(_)).into_inner();
+        let encoding: Option<ParameterEncoding> =
+            pop_feature!(param_features => Feature::ParameterEncoding(_)).into_inner();
+        let schema_ref: Option<SchemaRef> =
+            pop_feature!(param_features => Feature::SchemaRef(_)).into_inner();
+        let custom_extensions: Option<Extensions> =
+            pop_feature!(param_features => Feature::Extensions(_)).into_inner();
+        let deprecated_since: Option<DeprecatedSince> =
+            pop_feature!(param_features => Feature::DeprecatedSince(_)).into_inner();
+        let example_url: Option<ExampleUrl> =
+            pop_feature!(param_features => Feature::ExampleUrl(_)).into_inner();
+        let deprecated_reason = param_features.iter().find_map(|feature| match feature {
+            Feature::Deprecated(deprecated) => deprecated.reason().cloned(),
+            _ => None,
+        });
+        let description_template: Option<DescriptionTemplate> =
+            pop_feature!(param_features => Feature::DescriptionTemplate(_)).into_inner();
+        if let Some(description_template) = description_template {
+            param_features.push(Feature::Description(
+                description_template.expand(self.documented_name()),
+            ));
+        }
+        param_features.extend(self.cookie_array_features(&param_features)?);
+        let (aliases, mut param_features): (Vec<Feature>, Vec<Feature>) = param_features
+            .into_iter()
+            .partition(|feature| matches!(feature, Feature::Alias(_)));
+        param_features.sort_by_key(Self::feature_order);
+        let aliases = aliases
+            .iter()
+            .filter_map(|feature| match feature {
+                Feature::Alias(alias) => Some(alias.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
 
         tokens.extend(param_features.to_token_stream()?);
 
-        if !schema_features.is_empty() && self.parameter_schema.is_none() {
+        // explicitly defined `deprecated` always wins over deprecation of the parameter type
+        if !param_features
+            .iter()
+            .any(|feature| matches!(feature, Feature::Deprecated(_)))
+        {
+            if let Some(deprecated) = self
+                .parameter_schema
+                .as_ref()
+                .filter(|_| content.is_none() && schema_ref.is_none())
+                .and_then(ParameterSchema::deprecated_tokens)
+            {
+                tokens.extend(quote! { .deprecated(#deprecated) });
+            }
+        }
+
+        let mut extensions = TokenStream::new();
+        // OpenAPI does not have a field for deprecation reason, thus it is added as extension
+        if let Some(reason) = deprecated_reason {
+            extensions.extend(quote! { .add("x-deprecated-reason", #reason) });
+        }
+        if let Some(since) = deprecated_since {
+            extensions.extend(quote! { .add("x-deprecated-since", #since) });
+        }
+        if let Some(same_site) = same_site {
+            extensions.extend(quote! { .add("x-samesite", #same_site) });
+        }
+        if let Some(http_only) = http_only {
+            extensions.extend(quote! { .add("x-http-only", #http_only) });
+        }
+        if let Some(example_url) = example_url {
+            extensions.extend(quote! { .add("x-example-url", #example_url) });
+        }
+        if !aliases.is_empty() {
+            extensions.extend(quote! { .add("x-aliases", vec![#(#aliases),*]) });
+        }
+        if let Some(required_group) = &self.required_group {
+            extensions.extend(quote! { .add("x-required-group", #required_group) });
+        }
+        custom_extensions.to_tokens(&mut extensions);
+        if !extensions.is_empty() {
+            tokens.extend(quote! {
+                .extensions(Some(
+                    utoipa::openapi::extensions::ExtensionsBuilder::new()
+                        #extensions
+                        .build()
+                ))
+            });
+        }
+
+        // style of the parameter falls back to default style of the location when not defined
+        let style = param_features
+            .iter()
+            .find_map(|feature| match feature {
+                Feature::Style(style) => Some(style.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| self.parameter_in.default_style().into());
+        if matches!(style.get_style(), ParameterStyle::DeepObject) {
+            let is_object = match &self.parameter_schema {
+                Some(parameter_schema) => parameter_schema.is_object()?,
+                None => false,
+            };
+            if !is_object {
+                return Err(Diagnostics::with_span(
+                    style.span(),
+                    format!(
+                        "`DeepObject` style can only be used with object types, but parameter `{}` is not an object",
+                        self.name
+                    ),
+                )
+                .help("Try using a struct implementing `ToSchema` or a map as the parameter type"));
+            }
+        }
+
+        let mut schema_features = schema_features.clone();
+        let schema_type: Option<SchemaTypeOverride> =
+            pop_feature!(schema_features => Feature::SchemaTypeOverride(_)).into_inner();
+
+        if !schema_features.is_empty() && self.parameter_schema.is_none() && schema_type.is_none() {
             return Err(
-                Diagnostics::new("Missing `parameter_type` attribute, cannot define schema features without it.")
+                Diagnostics::with_span(self.span(), "Missing `parameter_type` attribute, cannot define schema features without it.")
                 .help("See docs for more details <https://docs.rs/utoipa/latest/utoipa/attr.path.html#parameter-type-attributes>")
             );
         }
 
-        if let Some(parameter_schema) = &self.parameter_schema {
-            parameter_schema.to_tokens(tokens)?;
+        if let Some(content) = &content {
+            let (media_type, parsed_type) = content.media_type();
+            let parameter_schema = ParameterSchema {
+                parameter_type: ParameterType::Parsed(parsed_type.clone()),
+                features: Vec::new(),
+            };
+            let (schema, required) = parameter_schema.to_schema_tokens(Vec::new())?;
+            let required: Required = required.into();
+
+            tokens.extend(quote! {
+                .content(
+                    #media_type,
+                    utoipa::openapi::content::ContentBuilder::new()
+                        .schema(Some(#schema))
+                        #encoding
+                        .build()
+                )
+                .required(#required)
+            });
+        } else if let Some(schema_ref) = schema_ref {
+            // required status is still resolved from the parameter type if available
+            let is_option = self
+                .parameter_schema
+                .as_ref()
+                .map_or(Ok(false), ParameterSchema::is_option)?;
+            let required: Required = (!is_option).into();
+            tokens.extend(quote! { .schema(Some(#schema_ref)).required(#required) });
+        } else if let Some(parameter_schema) = &self.parameter_schema {
+            parameter_schema.to_tokens_with_encoding(tokens, encoding.as_ref())?;
+        } else if let Some(schema_type) = schema_type {
+            ParameterSchema::validate_date_format_bounds(&schema_features)?;
+            let schema =
+                ParameterSchema::schema_type_override_tokens(schema_type, false, schema_features)?;
+            tokens.extend(quote! { .schema(Some(#schema)) });
+        } else if !no_schema.is_some_and(|no_schema| no_schema.is_true()) {
+            // parameter without a type defaults to `string` schema
+            tokens.extend(quote! {
+                .schema(Some(
+                    utoipa::openapi::schema::ObjectBuilder::new()
+                        .schema_type(utoipa::openapi::schema::Type::String)
+                ))
+            });
+        }
+
+        // explicitly defined required will override the one resolved from parameter type
+        if let Some(required) = required {
+            let required: Required = required.into();
+            tokens.extend(quote! { .required(#required) });
         }
 
         Ok(())
@@ -398,6 +1776,7 @@ impl ToTokensDiagnostics for ValueParameter<'_> {
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
 pub struct IntoParamsIdentParameter<'i> {
     pub path: Cow<'i, syn::Path>,
     /// quote!{ ... } of function which should implement `parameter_in_provider` for [`utoipa::IntoParams::into_param`]
@@ -423,17 +1802,95 @@ impl PartialEq for IntoParamsIdentParameter<'_> {
 
 impl Eq for IntoParamsIdentParameter<'_> {}
 
+/// Reference to reusable parameter in components parsed from `ref = "Name"` syntax.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct ParameterRef {
+    name: String,
+    span: Span,
+}
+
+impl PartialEq for ParameterRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for ParameterRef {}
+
+impl Parse for ParameterRef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![ref]>()?;
+        let name = parse_utils::parse_next(input, || input.parse::<LitStr>()).map_err(|error| {
+            Error::new(
+                error.span(),
+                format!(r#"expected parameter name e.g. `ref = "Name"`, {error}"#),
+            )
+        })?;
+
+        Ok(Self {
+            name: name.value(),
+            span: name.span(),
+        })
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Clone)]
 pub enum ParameterIn {
     Query,
     Path,
     Header,
     Cookie,
+    /// Non-standard parameter location defined with `in = "..."`.
+    Other(String),
 }
 
 impl ParameterIn {
     pub const VARIANTS: &'static [Self] = &[Self::Query, Self::Path, Self::Header, Self::Cookie];
+
+    /// Get OpenAPI name of the [`ParameterIn`] e.g. `"query"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Query => "query",
+            Self::Path => "path",
+            Self::Header => "header",
+            Self::Cookie => "cookie",
+            Self::Other(location) => location,
+        }
+    }
+
+    /// Get default [`ParameterStyle`] of the [`ParameterIn`] which applies when `style` is not
+    /// defined e.g. [`ParameterStyle::Form`] for [`ParameterIn::Query`].
+    pub fn default_style(&self) -> ParameterStyle {
+        match self {
+            Self::Query | Self::Cookie => ParameterStyle::Form,
+            Self::Path | Self::Header | Self::Other(_) => ParameterStyle::Simple,
+        }
+    }
+}
+
+impl FromStr for ParameterIn {
+    type Err = Error;
+
+    /// Parse [`ParameterIn`] from OpenAPI name e.g. `"query"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ParameterIn::VARIANTS
+            .iter()
+            .find(|variant| variant.as_str() == s)
+            .cloned()
+            .ok_or_else(|| {
+                let expected_one_of = ParameterIn::VARIANTS
+                    .iter()
+                    .map(|variant| format!(r#""{}""#, variant.as_str()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Error::new(
+                    Span::call_site(),
+                    format!("unexpected in, expected one of: {expected_one_of}"),
+                )
+            })
+    }
 }
 
 impl Display for ParameterIn {
@@ -443,6 +1900,7 @@ impl Display for ParameterIn {
             ParameterIn::Path => write!(f, "Path"),
             ParameterIn::Header => write!(f, "Header"),
             ParameterIn::Cookie => write!(f, "Cookie"),
+            ParameterIn::Other(location) => write!(f, "{location}"),
         }
     }
 }
@@ -453,26 +1911,49 @@ impl Default for ParameterIn {
     }
 }
 
-impl Parse for ParameterIn {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
-        fn expected_style() -> String {
-            let variants: String = ParameterIn::VARIANTS
-                .iter()
-                .map(ToString::to_string)
-                .collect::<Vec<_>>()
-                .join(", ");
-            format!("unexpected in, expected one of: {variants}")
-        }
-        let style = input.parse::<Ident>()?;
-
-        match &*style.to_string() {
+impl ParameterIn {
+    /// Construct [`ParameterIn`] from parsed [`Ident`]. Error is reported at the span of the
+    /// given [`Ident`] if it is not a valid variant.
+    pub fn from_attr_ident(ident: &Ident) -> syn::Result<Self> {
+        match &*ident.to_string() {
             "Path" => Ok(Self::Path),
             "Query" => Ok(Self::Query),
             "Header" => Ok(Self::Header),
             "Cookie" => Ok(Self::Cookie),
-            _ => Err(Error::new(style.span(), expected_style())),
+            _ => {
+                let variants: String = ParameterIn::VARIANTS
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Err(Error::new(
+                    ident.span(),
+                    format!("unexpected in, expected one of: {variants}"),
+                ))
+            }
         }
     }
+
+    /// Construct [`ParameterIn`] from location string of _`in = "..."`_. Standard OpenAPI
+    /// locations e.g. `"query"` resolve to their variants and any other location is
+    /// [`ParameterIn::Other`].
+    pub fn from_location(location: &LitStr) -> syn::Result<Self> {
+        let value = location.value();
+        if value.is_empty() || value.contains(char::is_whitespace) {
+            return Err(Error::new(
+                location.span(),
+                r#"expected parameter location without whitespace e.g. `in = "body"`"#,
+            ));
+        }
+
+        Ok(value.parse().unwrap_or(Self::Other(value)))
+    }
+}
+
+impl Parse for ParameterIn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Self::from_attr_ident(&input.parse::<Ident>()?)
+    }
 }
 
 impl ToTokens for ParameterIn {
@@ -482,6 +1963,9 @@ impl ToTokens for ParameterIn {
             Self::Query => quote! { utoipa::openapi::path::ParameterIn::Query },
             Self::Header => quote! { utoipa::openapi::path::ParameterIn::Header },
             Self::Cookie => quote! { utoipa::openapi::path::ParameterIn::Cookie },
+            Self::Other(location) => {
+                quote! { utoipa::openapi::path::ParameterIn::custom(#location) }
+            }
         })
     }
 }
@@ -499,10 +1983,50 @@ pub enum ParameterStyle {
     DeepObject,
 }
 
+impl ParameterStyle {
+    /// Check whether the style is only applicable to [`ParameterIn::Path`] parameters.
+    pub fn is_path_only(&self) -> bool {
+        matches!(self, Self::Matrix | Self::Label)
+    }
+
+    /// Check whether the style is only applicable to [`ParameterIn::Query`] and
+    /// [`ParameterIn::Cookie`] parameters.
+    pub fn is_query_or_cookie_only(&self) -> bool {
+        matches!(self, Self::Form)
+    }
+
+    /// Check whether the style is only applicable to [`ParameterIn::Query`] parameters.
+    pub fn is_query_only(&self) -> bool {
+        matches!(
+            self,
+            Self::SpaceDelimited | Self::PipeDelimited | Self::DeepObject
+        )
+    }
+}
+
 impl Parse for ParameterStyle {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         const EXPECTED_STYLE: &str =  "unexpected style, expected one of: Matrix, Label, Form, Simple, SpaceDelimited, PipeDelimited, DeepObject";
-        let style = input.parse::<Ident>()?;
+        const EXPECTED_STYLE_STR: &str =  "unexpected style, expected one of: matrix, label, form, simple, spaceDelimited, pipeDelimited, deepObject";
+
+        if input.peek(LitStr) {
+            let style = input.parse::<LitStr>()?;
+
+            return match &*style.value() {
+                "matrix" => Ok(ParameterStyle::Matrix),
+                "label" => Ok(ParameterStyle::Label),
+                "form" => Ok(ParameterStyle::Form),
+                "simple" => Ok(ParameterStyle::Simple),
+                "spaceDelimited" => Ok(ParameterStyle::SpaceDelimited),
+                "pipeDelimited" => Ok(ParameterStyle::PipeDelimited),
+                "deepObject" => Ok(ParameterStyle::DeepObject),
+                _ => Err(Error::new(style.span(), EXPECTED_STYLE_STR)),
+            };
+        }
+
+        let style = input
+            .parse::<Ident>()
+            .map_err(|error| Error::new(error.span(), EXPECTED_STYLE))?;
 
         match &*style.to_string() {
             "Matrix" => Ok(ParameterStyle::Matrix),
@@ -544,3 +2068,1680 @@ impl ToTokens for ParameterStyle {
         }
     }
 }
+
+/// Rendering of [`ValueParameter`] to OpenAPI JSON without going through the code generation.
+///
+/// This allows asserting the parameter output in unit tests without a compile step. The value
+/// has the same structure as the serialized [`utoipa::openapi::path::Parameter`] built by the
+/// generated code with following limitations:
+///
+/// * Only literal values are included e.g. `description = "..."` but not `description = expr`.
+/// * Schema only contains the type of the parameter, schema attributes e.g. `minimum` and
+///   `example` are not included.
+#[cfg(test)]
+impl ValueParameter<'_> {
+    pub(crate) fn to_openapi_value(&self) -> serde_json::Value {
+        use serde_json::{json, Map, Value};
+
+        fn literal<T: syn::parse::Parse>(value: &impl ToTokens) -> Option<T> {
+            syn::parse2(value.to_token_stream()).ok()
+        }
+        fn bool_literal(value: &impl ToTokens) -> bool {
+            literal::<syn::LitBool>(value).is_some_and(|value| value.value)
+        }
+
+        let mut parameter = Map::new();
+        let mut extensions = Map::new();
+        parameter.insert("name".into(), json!(self.documented_name()));
+        parameter.insert("in".into(), json!(self.parameter_in.as_str()));
+
+        let (_, param_features) = &self.features;
+        let cookie_array_features = self
+            .cookie_array_features(param_features)
+            .unwrap_or_default();
+        let mut required = None;
+        let mut aliases = Vec::new();
+        for feature in param_features.iter().chain(&cookie_array_features) {
+            match feature {
+                Feature::Description(description) => {
+                    if let Some(description) = literal::<LitStr>(description) {
+                        parameter.insert("description".into(), json!(description.value()));
+                    }
+                }
+                Feature::DescriptionTemplate(template) => {
+                    if let Some(description) =
+                        literal::<LitStr>(&template.expand(self.documented_name()))
+                    {
+                        parameter.insert("description".into(), json!(description.value()));
+                    }
+                }
+                Feature::Deprecated(deprecated) => {
+                    parameter.insert("deprecated".into(), json!(deprecated.is_true()));
+                    if let Some(reason) = deprecated.reason() {
+                        extensions.insert("x-deprecated-reason".into(), json!(reason.value()));
+                    }
+                }
+                Feature::DeprecatedSince(since) => {
+                    if let Some(since) = literal::<LitStr>(since) {
+                        extensions.insert("x-deprecated-since".into(), json!(since.value()));
+                    }
+                }
+                Feature::Style(style) => {
+                    let style = match style.get_style() {
+                        ParameterStyle::Matrix => "matrix",
+                        ParameterStyle::Label => "label",
+                        ParameterStyle::Form => "form",
+                        ParameterStyle::Simple => "simple",
+                        ParameterStyle::SpaceDelimited => "spaceDelimited",
+                        ParameterStyle::PipeDelimited => "pipeDelimited",
+                        ParameterStyle::DeepObject => "deepObject",
+                    };
+                    parameter.insert("style".into(), json!(style));
+                }
+                Feature::Explode(explode) => {
+                    parameter.insert("explode".into(), json!(bool_literal(explode)));
+                }
+                Feature::AllowReserved(allow_reserved) => {
+                    parameter.insert("allowReserved".into(), json!(bool_literal(allow_reserved)));
+                }
+                Feature::AllowEmptyValue(allow_empty_value) => {
+                    parameter.insert(
+                        "allowEmptyValue".into(),
+                        json!(bool_literal(allow_empty_value)),
+                    );
+                }
+                Feature::CookieSameSite(same_site) => {
+                    if let Some(same_site) = literal::<LitStr>(same_site) {
+                        extensions.insert("x-samesite".into(), json!(same_site.value()));
+                    }
+                }
+                Feature::CookieHttpOnly(http_only) => {
+                    extensions.insert("x-http-only".into(), json!(bool_literal(http_only)));
+                }
+                Feature::ExampleUrl(example_url) => {
+                    if let Some(example_url) = literal::<LitStr>(example_url) {
+                        extensions.insert("x-example-url".into(), json!(example_url.value()));
+                    }
+                }
+                Feature::Alias(alias) => aliases.push(alias.as_str().to_string()),
+                Feature::Required(explicit) => required = Some(explicit.is_true()),
+                _ => (),
+            }
+        }
+        if !aliases.is_empty() {
+            extensions.insert("x-aliases".into(), json!(aliases));
+        }
+        if let Some(required_group) = &self.required_group {
+            extensions.insert("x-required-group".into(), json!(required_group.value()));
+        }
+
+        let schema = match &self.parameter_schema {
+            Some(parameter_schema) => {
+                let mut schema = |type_tree: &TypeTree| {
+                    required = required.or(Some(!type_tree.is_option()));
+                    Self::schema_value(type_tree)
+                };
+                match &parameter_schema.parameter_type {
+                    #[cfg(any(
+                        feature = "actix_extras",
+                        feature = "rocket_extras",
+                        feature = "axum_extras"
+                    ))]
+                    ParameterType::External(type_tree) => Some(schema(type_tree)),
+                    ParameterType::Parsed(inline_type) => {
+                        TypeTree::from_type(inline_type.ty.as_ref())
+                            .ok()
+                            .map(|type_tree| schema(&type_tree))
+                    }
+                }
+            }
+            None => Some(json!({ "type": "string" })),
+        };
+        // `Path` parameter is always required when required status is defined
+        let required =
+            required.is_some_and(|required| required || self.parameter_in == ParameterIn::Path);
+        parameter.insert("required".into(), json!(required));
+        if let Some(schema) = schema {
+            parameter.insert("schema".into(), schema);
+        }
+        parameter.extend(extensions);
+
+        Value::Object(parameter)
+    }
+
+    /// Render schema of the type of the parameter following [`ComponentSchema`] where
+    /// non-primitive types are referenced.
+    fn schema_value(type_tree: &TypeTree) -> serde_json::Value {
+        use serde_json::json;
+
+        let child = || {
+            type_tree
+                .children
+                .as_deref()
+                .and_then(|children| children.last())
+                .map_or(json!({}), Self::schema_value)
+        };
+
+        match type_tree.generic_type {
+            Some(GenericType::Option) => match child() {
+                serde_json::Value::Object(mut schema) if schema.contains_key("type") => {
+                    let schema_type = schema.remove("type").unwrap_or_default();
+                    schema.insert("type".into(), json!([schema_type, "null"]));
+                    serde_json::Value::Object(schema)
+                }
+                schema => json!({ "oneOf": [{ "type": "null" }, schema] }),
+            },
+            Some(GenericType::Box | GenericType::Cow | GenericType::RefCell) => child(),
+            #[cfg(feature = "rc_schema")]
+            Some(GenericType::Arc | GenericType::Rc) => child(),
+            Some(GenericType::Vec | GenericType::LinkedList) => {
+                json!({ "type": "array", "items": child() })
+            }
+            #[cfg(feature = "smallvec")]
+            Some(GenericType::SmallVec) => json!({ "type": "array", "items": child() }),
+            Some(GenericType::Set) => {
+                json!({ "type": "array", "items": child(), "uniqueItems": true })
+            }
+            Some(GenericType::Map) => {
+                json!({ "type": "object", "additionalProperties": child() })
+            }
+            None => {
+                let Some(path) = type_tree.path.as_deref() else {
+                    return json!({});
+                };
+                let schema_type = SchemaType {
+                    path: Cow::Borrowed(path),
+                    nullable: false,
+                };
+                let format = match crate::schema_type::KnownFormat::from_path(path) {
+                    Ok(crate::schema_type::KnownFormat::Int32) => Some("int32"),
+                    Ok(crate::schema_type::KnownFormat::Int64) => Some("int64"),
+                    Ok(crate::schema_type::KnownFormat::Float) => Some("float"),
+                    Ok(crate::schema_type::KnownFormat::Double) => Some("double"),
+                    _ => None,
+                };
+                let schema_type = if schema_type.is_string() {
+                    "string"
+                } else if schema_type.is_integer() {
+                    "integer"
+                } else if schema_type.is_number() {
+                    "number"
+                } else if schema_type.is_boolean() {
+                    "boolean"
+                } else if type_tree.value_type == ValueType::Object {
+                    let name = path
+                        .segments
+                        .last()
+                        .map(|segment| segment.ident.to_string())
+                        .unwrap_or_default();
+                    return json!({ "$ref": format!("#/components/schemas/{name}") });
+                } else {
+                    return json!({});
+                };
+
+                match format {
+                    Some(format) => json!({ "type": schema_type, "format": format }),
+                    None => json!({ "type": schema_type }),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parameter_with_optional_array_type() {
+        let parameter = syn::parse_str::<ValueParameter>(r#"("ids" = Option<Vec<String>>, Query)"#)
+            .expect("optional array parameter should parse");
+
+        let tokens = parameter.try_to_token_stream().unwrap().to_string();
+        assert!(tokens.contains("ParameterIn :: Query"), "{tokens}");
+        assert!(tokens.contains("ArrayBuilder"), "{tokens}");
+        assert!(
+            tokens.contains(". required (utoipa :: openapi :: Required :: False)"),
+            "{tokens}"
+        );
+    }
+
+    #[test]
+    fn parameter_with_array_of_optional_type() {
+        let parameter = syn::parse_str::<ValueParameter>(r#"("ids" = Vec<Option<String>>, Query)"#)
+            .expect("array of optional parameter should parse");
+
+        let tokens = parameter.try_to_token_stream().unwrap().to_string();
+        assert!(tokens.contains("ArrayBuilder"), "{tokens}");
+        assert!(
+            tokens.contains(". required (utoipa :: openapi :: Required :: True)"),
+            "{tokens}"
+        );
+    }
+
+    #[test]
+    fn parse_parameter_with_module_path_type() {
+        let parameter = syn::parse_str::<ValueParameter>(r#"("id" = crate::models::Id, Path)"#)
+            .expect("parameter with module path type should parse");
+
+        let tokens = parameter.try_to_token_stream().unwrap().to_string();
+        assert!(
+            tokens.contains("< crate :: models :: Id as utoipa :: ToSchema > :: name ()"),
+            "{tokens}"
+        );
+        assert!(
+            tokens.contains(". required (utoipa :: openapi :: Required :: True)"),
+            "{tokens}"
+        );
+    }
+
+    #[test]
+    fn parameter_in_as_str_round_trip() {
+        for parameter_in in ParameterIn::VARIANTS {
+            assert_eq!(
+                ParameterIn::from_str(parameter_in.as_str()).unwrap(),
+                *parameter_in
+            );
+        }
+        assert_eq!(ParameterIn::Header.as_str(), "header");
+
+        let Err(error) = ParameterIn::from_str("Query") else {
+            panic!("attribute name of parameter in should not be parsed");
+        };
+        assert_eq!(
+            error.to_string(),
+            r#"unexpected in, expected one of: "query", "path", "header", "cookie""#
+        );
+    }
+
+    #[test]
+    fn parse_parameter_ref_without_name_fails() {
+        let Err(error) = syn::parse_str::<Parameter>("ref = Page") else {
+            panic!("parameter ref without literal name should fail");
+        };
+
+        assert!(
+            error
+                .to_string()
+                .starts_with(r#"expected parameter name e.g. `ref = "Name"`"#),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn parse_default_without_value_fails() {
+        let Err(error) = syn::parse_str::<ValueParameter>(r#"("limit" = i32, Query, default)"#)
+        else {
+            panic!("default without value should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "`default` requires a value for parameters, e.g. `default = 20`"
+        );
+    }
+
+    #[test]
+    fn parse_invalid_content_type_fails() {
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("filter" = String, Query, content_type = "json")"#,
+        ) else {
+            panic!("content_type without subtype should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "unexpected content_type, expected media type e.g. `application/json`"
+        );
+    }
+
+    #[test]
+    fn parse_encoding_without_content_fails() {
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("filter" = String, Query, encoding = { "tags": { style = Form } })"#,
+        ) else {
+            panic!("encoding without content should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "`encoding` can only be used together with `content_type` or `content`"
+        );
+    }
+
+    #[test]
+    fn parse_invalid_encoding_fails() {
+        for (input, expected) in [
+            (
+                r#"("filter" = String, Query, content_type = "application/json", encoding = {})"#,
+                r#"expected at least one property in `encoding` e.g. `encoding = { "tags": { style = Form } }`"#,
+            ),
+            (
+                r#"("filter" = String, Query, content_type = "application/json", encoding = { "tags": { explode, explode }, "tags": { style = Form } })"#,
+                "duplicate property `tags` in `encoding`",
+            ),
+            (
+                r#"("filter" = String, Query, content_type = "application/json", encoding = { "tags": { headers = true } })"#,
+                "unexpected attribute, expected any of: content_type, style, explode, allow_reserved",
+            ),
+        ] {
+            let Err(error) = syn::parse_str::<ValueParameter>(input) else {
+                panic!("invalid encoding should fail: {input}");
+            };
+            assert_eq!(error.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn parse_example_with_examples_fails() {
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("id" = i32, Query, example = 1, examples(("one" = (value = json!(1)))))"#,
+        ) else {
+            panic!("example with examples should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "`example` and `examples` are mutually exclusive, define only one of them"
+        );
+    }
+
+    #[test]
+    fn parse_name_and_type_without_parameter_in() {
+        for input in [r#"("id" = String)"#, r#"("id" = String,)"#, r#"("id")"#] {
+            let parameter = syn::parse_str::<ValueParameter>(input).unwrap();
+
+            assert!(parameter.parameter_in == ParameterIn::Path, "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_grouped_bare_names_use_group_parameter_in() {
+        let ParameterList(parameters) =
+            syn::parse_str::<ParameterList>(r#"(Query, "page", "size")"#).unwrap();
+
+        assert_eq!(parameters.len(), 2);
+        for parameter in &parameters {
+            let Parameter::Value(parameter) = parameter else {
+                panic!("grouped parameter should be value parameter");
+            };
+            assert!(parameter.parameter_in == ParameterIn::Query);
+            assert!(parameter.parameter_schema.is_none());
+        }
+
+        let ParameterList(parameters) = syn::parse_str::<ParameterList>(r#"("page")"#).unwrap();
+        let Parameter::Value(parameter) = &parameters[0] else {
+            panic!("standalone parameter should be value parameter");
+        };
+        assert!(parameter.parameter_in == ParameterIn::Path);
+    }
+
+    #[test]
+    fn parse_grouped_parameters_with_required_group() {
+        let ParameterList(parameters) = syn::parse_str::<ParameterList>(
+            r#"(Query, required_group = "dates", "from" = String, "to" = String)"#,
+        )
+        .unwrap();
+
+        assert_eq!(parameters.len(), 2);
+        for parameter in &parameters {
+            let Parameter::Value(parameter) = parameter else {
+                panic!("grouped parameter should be value parameter");
+            };
+            assert_eq!(
+                parameter
+                    .required_group
+                    .as_ref()
+                    .map(LitStr::value)
+                    .as_deref(),
+                Some("dates")
+            );
+        }
+
+        for (input, expected) in [
+            (
+                r#"(Query, required_group = "dates", "from" = String)"#,
+                "required group `dates` must contain at least two parameters",
+            ),
+            (
+                r#"(Query, required_group = "", "from", "to")"#,
+                r#"expected required group name without whitespace e.g. `required_group = "dates"`"#,
+            ),
+            (
+                r#"(Query, group = "dates", "from", "to")"#,
+                "unexpected attribute `group`, expected `required_group`",
+            ),
+        ] {
+            let Err(error) = syn::parse_str::<ParameterList>(input) else {
+                panic!("invalid required group should fail: {input}");
+            };
+            assert_eq!(error.to_string(), expected, "{input}");
+        }
+
+        let lists = [
+            r#"(Query, required_group = "dates", "from", "to")"#,
+            r#"(Query, required_group = "dates", "since", "until")"#,
+        ]
+        .map(|input| syn::parse_str::<ParameterList>(input).unwrap());
+        assert_eq!(
+            ParameterList::validate_required_groups(&lists)
+                .unwrap_err()
+                .to_string(),
+            "required group `dates` is defined multiple times, define all parameters of the group in single grouped parameter list"
+        );
+    }
+
+    #[test]
+    fn parse_cookie_attributes_on_non_cookie_parameter_fails() {
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("session" = String, Header, x_samesite = "Strict", x_http_only)"#,
+        ) else {
+            panic!("cookie attributes on header parameter should fail");
+        };
+
+        assert_eq!(
+            error.into_iter().map(|error| error.to_string()).collect::<Vec<_>>(),
+            [
+                "`x_samesite` is only applicable to `Cookie` parameters, but parameter `session` is in `Header`",
+                "`x_http_only` is only applicable to `Cookie` parameters, but parameter `session` is in `Header`"
+            ]
+        );
+
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("session" = String, Cookie, x_samesite = "strict")"#,
+        ) else {
+            panic!("invalid x_samesite should fail");
+        };
+        assert_eq!(
+            error.to_string(),
+            r#"unexpected `x_samesite`, expected one of: "Strict", "Lax", "None""#
+        );
+    }
+
+    #[test]
+    fn parse_read_only_with_write_only_fails() {
+        let Err(error) =
+            syn::parse_str::<ValueParameter>(r#"("id" = i32, Query, read_only, write_only)"#)
+        else {
+            panic!("read_only with write_only should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "`read_only` and `write_only` are mutually exclusive, define only one of them"
+        );
+        assert_eq!(error.span().start().column, 31);
+
+        let Err(error) =
+            syn::parse_str::<ValueParameter>(r#"("id" = i32, Query, write_only, read_only)"#)
+        else {
+            panic!("write_only with read_only should fail");
+        };
+        assert_eq!(error.span().start().column, 32);
+    }
+
+    #[test]
+    fn parse_read_only_with_false_write_only() {
+        let parameter = syn::parse_str::<ValueParameter>(
+            r#"("id" = i32, Query, read_only, write_only = false)"#,
+        )
+        .unwrap();
+
+        assert_eq!(parameter.features.0.len(), 2);
+    }
+
+    #[test]
+    fn xml_attribute_on_array_parameter_fails() {
+        let parameter =
+            syn::parse_str::<Parameter>(r#"("ids" = Vec<i32>, Query, xml(attribute))"#).unwrap();
+
+        let Err(diagnostics) = parameter.try_to_token_stream() else {
+            panic!("xml attribute on array should fail");
+        };
+
+        assert_eq!(
+            diagnostics.message(),
+            "cannot use `attribute` attribute in slice field type"
+        );
+    }
+
+    #[test]
+    fn validate_parameter_against_path_template() {
+        let path = "/items/{id}/tags/{tag:.*}";
+        let parse = |input: &str| syn::parse_str::<Parameter>(input).unwrap();
+
+        assert!(parse(r#"("id", Path)"#).validate_against_path(path).is_ok());
+        assert!(parse(r#"("tag", Path)"#)
+            .validate_against_path(path)
+            .is_ok());
+        assert!(parse(r#"("limit", Query)"#)
+            .validate_against_path(path)
+            .is_ok());
+        assert!(parse("Filter").validate_against_path(path).is_ok());
+
+        assert_eq!(
+            parse(r#"("item_id", Path)"#)
+                .validate_against_path(path)
+                .unwrap_err()
+                .to_string(),
+            "`Path` parameter `item_id` is not found in path `/items/{id}/tags/{tag:.*}`, expected path to contain `{item_id}`"
+        );
+        assert_eq!(
+            parse(r#"("id", Query)"#)
+                .validate_against_path(path)
+                .unwrap_err()
+                .to_string(),
+            "parameter `id` is in `Query` but path `/items/{id}/tags/{tag:.*}` has `{id}` segment, did you mean `Path`?"
+        );
+    }
+
+    #[test]
+    fn validate_parameter_against_path_with_escaped_braces() {
+        let path = "/items/{{literal}}/{id}";
+        let parse = |input: &str| syn::parse_str::<Parameter>(input).unwrap();
+
+        assert_eq!(Parameter::path_template_names(path), ["id"]);
+        assert_eq!(
+            Parameter::path_template_names("/a/{{{id}}}/{name:[a-z]+}/}}{{"),
+            ["id", "name"]
+        );
+
+        assert!(parse(r#"("id", Path)"#).validate_against_path(path).is_ok());
+        assert!(parse(r#"("literal", Query)"#)
+            .validate_against_path(path)
+            .is_ok());
+        assert_eq!(
+            parse(r#"("literal", Path)"#)
+                .validate_against_path(path)
+                .unwrap_err()
+                .to_string(),
+            "`Path` parameter `literal` is not found in path `/items/{{literal}}/{id}`, expected path to contain `{literal}`"
+        );
+    }
+
+    #[test]
+    fn deep_object_style_on_primitive_type_fails() {
+        for input in [
+            r#"("filter" = String, Query, style = DeepObject, explode)"#,
+            r#"("filter" = Vec<String>, Query, style = "deepObject", explode)"#,
+            r#"("filter", Query, style = DeepObject)"#,
+        ] {
+            let parameter = syn::parse_str::<Parameter>(input).unwrap();
+            let Err(diagnostics) = parameter.try_to_token_stream() else {
+                panic!("deep object style on non object type should fail: {input}");
+            };
+
+            assert_eq!(
+                diagnostics.message(),
+                "`DeepObject` style can only be used with object types, but parameter `filter` is not an object"
+            );
+        }
+    }
+
+    #[test]
+    fn deep_object_style_on_object_type() {
+        for input in [
+            r#"("filter" = Filter, Query, style = DeepObject, explode)"#,
+            r#"("filter" = Option<HashMap<String, String>>, Query, style = DeepObject)"#,
+        ] {
+            let parameter = syn::parse_str::<Parameter>(input).unwrap();
+
+            assert!(parameter.try_to_token_stream().is_ok(), "{input}");
+        }
+    }
+
+    #[test]
+    fn exploded_form_style_on_object_type() {
+        let parameter =
+            syn::parse_str::<Parameter>(r#"("filter" = Filter, Query, style = "form", explode)"#)
+                .unwrap();
+
+        let tokens = parameter.try_to_token_stream().unwrap().to_string();
+        assert!(
+            tokens.contains(". style (Some (utoipa :: openapi :: path :: ParameterStyle :: Form))"),
+            "{tokens}"
+        );
+        assert!(tokens.contains(". explode (Some (true))"), "{tokens}");
+        assert!(
+            tokens.contains("< Filter as utoipa :: ToSchema > :: name ()"),
+            "{tokens}"
+        );
+    }
+
+    #[test]
+    fn parameter_in_default_style() {
+        for (parameter_in, expected) in [
+            (ParameterIn::Query, "Form"),
+            (ParameterIn::Path, "Simple"),
+            (ParameterIn::Header, "Simple"),
+            (ParameterIn::Cookie, "Form"),
+        ] {
+            let style = parameter_in.default_style();
+            assert_eq!(
+                style.to_token_stream().to_string(),
+                format!("utoipa :: openapi :: path :: ParameterStyle :: {expected}")
+            );
+        }
+
+        let parameter = syn::parse_str::<Parameter>(r#"("id" = i32, Query)"#).unwrap();
+        let tokens = parameter.try_to_token_stream().unwrap().to_string();
+        assert!(!tokens.contains(". style ("), "{tokens}");
+    }
+
+    #[test]
+    fn form_style_outside_query_or_cookie_fails() {
+        for (input, parameter_in) in [
+            (
+                r#"("filter" = Filter, Path, style = Form, explode)"#,
+                "Path",
+            ),
+            (r#"("filter" = Filter, Header, style = "form")"#, "Header"),
+        ] {
+            let Err(error) = syn::parse_str::<ValueParameter>(input) else {
+                panic!("form style outside query or cookie should fail: {input}");
+            };
+
+            assert_eq!(
+                error.to_string(),
+                format!("`Form` style is only applicable to `Query` and `Cookie` parameters, but parameter `filter` is in `{parameter_in}`")
+            );
+        }
+    }
+
+    #[test]
+    fn query_only_style_outside_query_fails() {
+        for (input, parameter_in) in [
+            (
+                r#"("prefs" = Filter, Cookie, style = DeepObject, explode)"#,
+                "Cookie",
+            ),
+            (
+                r#"("prefs" = Vec<String>, Header, style = PipeDelimited)"#,
+                "Header",
+            ),
+            (
+                r#"("prefs" = Vec<String>, Path, style = "spaceDelimited")"#,
+                "Path",
+            ),
+        ] {
+            let Err(error) = syn::parse_str::<ValueParameter>(input) else {
+                panic!("query only style outside query should fail: {input}");
+            };
+
+            assert_eq!(
+                error.to_string(),
+                format!("`SpaceDelimited`, `PipeDelimited` and `DeepObject` styles are only applicable to `Query` parameters, but parameter `prefs` is in `{parameter_in}`")
+            );
+        }
+    }
+
+    #[test]
+    fn parse_invalid_schema_type_fails() {
+        let Err(error) =
+            syn::parse_str::<ValueParameter>(r#"("token" = Token, Header, schema_type = "array")"#)
+        else {
+            panic!("array schema_type should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "unexpected schema_type, expected one of: string, integer, number, boolean, object"
+        );
+    }
+
+    #[test]
+    fn parameter_span_points_to_name() {
+        let parameter = syn::parse_str::<Parameter>(r#"("id" = i32, Query)"#).unwrap();
+        assert_eq!(parameter.span().start().column, 1);
+
+        let parameter = syn::parse_str::<Parameter>(r#"ref = "Page""#).unwrap();
+        assert_eq!(parameter.span().start().column, 6);
+
+        let parameter = syn::parse_str::<Parameter>("Filter").unwrap();
+        assert_eq!(parameter.span().start().column, 0);
+    }
+
+    #[test]
+    fn parse_boolean_with_schema_type_fails() {
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("active", Query, schema_type = "string", boolean)"#,
+        ) else {
+            panic!("boolean with schema_type should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "`boolean` and `schema_type` are mutually exclusive, define only one of them"
+        );
+    }
+
+    #[test]
+    fn parse_const_defined_multiple_times_fails() {
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("X-API-Version" = String, Header, const = "2", const = "3")"#,
+        ) else {
+            panic!("multiple const should fail");
+        };
+
+        assert_eq!(error.to_string(), "`const` is already specified");
+    }
+
+    #[test]
+    fn parse_extension_without_x_prefix_fails() {
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("id" = i32, Path, extensions("x-gateway" = "internal", "gateway" = true))"#,
+        ) else {
+            panic!("extension without `x-` prefix should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "extension name `gateway` must start with `x-`"
+        );
+    }
+
+    #[test]
+    fn parse_extension_with_unsupported_value_fails() {
+        let Err(error) =
+            syn::parse_str::<ValueParameter>(r#"("id" = i32, Path, extensions("x-char" = 'c'))"#)
+        else {
+            panic!("extension with char value should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "unexpected extension value, expected literal string, number or boolean, found character literal"
+        );
+    }
+
+    #[test]
+    fn parse_unexpected_attribute_values_fail_with_found_token() {
+        let parse_error = |tokens: &str| match syn::parse_str::<ValueParameter>(tokens) {
+            Ok(_) => panic!("parameter `{tokens}` should fail to parse"),
+            Err(error) => error.to_string(),
+        };
+
+        assert_eq!(
+            parse_error(r#"("id" = i32, Path, description = 42)"#),
+            "expected literal string or expression argument, found integer literal"
+        );
+        assert_eq!(
+            parse_error(r#"("id" = i32, Path, description = true)"#),
+            "expected literal string or expression argument, found boolean literal"
+        );
+        assert_eq!(
+            parse_error(r#"("id" = i32, Path, minimum = "10")"#),
+            "expected integer or float literal, found literal string"
+        );
+        assert_eq!(
+            parse_error(r#"("id" = i32, Path, nullable = 1)"#),
+            "expected literal boolean, found integer literal"
+        );
+        assert_eq!(
+            parse_error(r#"("id" = i32, Path, pattern = [1, 2])"#),
+            "expected literal string, found array"
+        );
+        assert_eq!(
+            parse_error(r#"(42 = i32, Path)"#),
+            "unparsable parameter name, expected literal string, found integer literal"
+        );
+    }
+
+    #[test]
+    fn parse_map_type_parameter_without_style_fails() {
+        let Err(error) =
+            syn::parse_str::<ValueParameter>(r#"("data" = HashMap<String, Value>, Query)"#)
+        else {
+            panic!("map type query parameter without style should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "unsupported type for `Query` parameter `data`, `Path` and `Query` parameters must be primitive, array of primitives or object with supported serialization `style` e.g. `style = DeepObject`"
+        );
+
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("data" = Vec<HashMap<String, String>>, Query, style = Form)"#,
+        ) else {
+            panic!("array of maps query parameter should fail");
+        };
+        assert!(error
+            .to_string()
+            .starts_with("unsupported type for `Query` parameter `data`"));
+
+        for input in [
+            r#"("data" = HashMap<String, String>, Query, style = DeepObject)"#,
+            r#"("data" = Option<BTreeMap<String, i32>>, Path, style = Simple)"#,
+            r#"("data" = HashMap<String, String>, Header)"#,
+            r#"("data" = HashMap<String, String>, Query, schema_type = "string")"#,
+        ] {
+            assert!(syn::parse_str::<ValueParameter>(input).is_ok(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_since_without_deprecated_fails() {
+        for input in [
+            r#"("id" = i32, Path, since = "2.3.0")"#,
+            r#"("id" = i32, Path, deprecated = false, since = "2.3.0")"#,
+        ] {
+            let Err(error) = syn::parse_str::<ValueParameter>(input) else {
+                panic!("since without deprecated should fail: {input}");
+            };
+
+            assert_eq!(
+                error.to_string(),
+                r#"`since` can only be used with deprecated parameter, e.g. `deprecated, since = "2.3.0"`"#
+            );
+        }
+
+        assert!(syn::parse_str::<ValueParameter>(
+            r#"("id" = i32, Path, deprecated = "use uuid", since = "2.3.0")"#
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn parse_content_with_parameter_type_fails() {
+        for input in [
+            r#"("filter" = String, Query, content = { "text/csv": String })"#,
+            r#"("filter", Query, content = { "text/csv": String }, min_length = 1)"#,
+        ] {
+            let Err(error) = syn::parse_str::<ValueParameter>(input) else {
+                panic!("content with schema should fail: {input}");
+            };
+
+            assert_eq!(
+                error.to_string(),
+                "`content` cannot be used with parameter type or schema attributes, define the type of the media type in `content` instead"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_invalid_content_media_type_fails() {
+        let Err(error) =
+            syn::parse_str::<ValueParameter>(r#"("filter", Query, content = { "csv": String })"#)
+        else {
+            panic!("content with invalid media type should fail");
+        };
+        assert_eq!(
+            error.to_string(),
+            "unexpected content media type, expected media type e.g. `application/json`"
+        );
+
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("filter", Query, content = { "text/csv": String, "text/csv": Filter })"#,
+        ) else {
+            panic!("content with duplicate media type should fail");
+        };
+        assert_eq!(
+            error.to_string(),
+            "`content` of a parameter can only have one media type"
+        );
+
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("filter", Query, content = { "application/json": Filter, "text/csv": String })"#,
+        ) else {
+            panic!("content with multiple media types should fail");
+        };
+        assert_eq!(
+            error.to_string(),
+            "`content` of a parameter can only have one media type"
+        );
+
+        let Err(error) = syn::parse_str::<ValueParameter>(r#"("filter", Query, content = {})"#)
+        else {
+            panic!("content without media type should fail");
+        };
+        assert_eq!(
+            error.to_string(),
+            "expected one media type in `content` e.g. `content = { \"application/json\": Type }`"
+        );
+    }
+
+    #[test]
+    fn parse_schema_ref_with_schema_attributes_fails() {
+        for input in [
+            r#"("status" = inline(Status), Query, schema_ref = "Status")"#,
+            r#"("status" = Status, Query, schema_ref = "Status", nullable)"#,
+            r#"("status", Query, schema_ref = "Status", content = { "text/csv": String })"#,
+        ] {
+            let Err(error) = syn::parse_str::<ValueParameter>(input) else {
+                panic!("schema_ref with schema attributes should fail: {input}");
+            };
+
+            assert_eq!(
+                error.to_string(),
+                "`schema_ref` cannot be used with `inline(...)` type, `content` or schema attributes"
+            );
+        }
+    }
+
+    #[test]
+    fn parameter_numeric_example_keeps_literal_kind() {
+        for (input, expected) in [
+            (
+                r#"("price" = f64, Query, example = 1.0)"#,
+                ". example (Some (serde_json :: json ! (1.0)))",
+            ),
+            (
+                r#"("quantity" = i32, Query, example = 1)"#,
+                ". example (Some (serde_json :: json ! (1)))",
+            ),
+        ] {
+            let parameter = syn::parse_str::<ValueParameter>(input).unwrap();
+            let tokens = parameter.try_to_token_stream().unwrap().to_string();
+
+            assert!(tokens.contains(expected), "{tokens}");
+        }
+    }
+
+    #[test]
+    fn parse_parameter_reports_all_feature_errors() {
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("id" = String, Path, allow_reserved, since = "2.3.0")"#,
+        ) else {
+            panic!("parameter with invalid features should fail");
+        };
+
+        assert_eq!(
+            error.into_iter().map(|error| error.to_string()).collect::<Vec<_>>(),
+            [
+                "`allow_reserved` is only applicable to `Query` parameters, but parameter `id` is in `Path`",
+                r#"`since` can only be used with deprecated parameter, e.g. `deprecated, since = "2.3.0"`"#
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_all_parameter_lists_reports_all_errors() {
+        use syn::parse::Parser;
+        let parse_all = |input: ParseStream| ParameterList::parse_all(input);
+
+        let Err(error) = parse_all.parse_str(
+            r#"("id" = String, Path, allow_reserved), ("page" = i32, Query), ("size" = i32, Header, style = Form)"#,
+        ) else {
+            panic!("parameter lists with invalid parameters should fail");
+        };
+        assert_eq!(
+            error.into_iter().map(|error| error.to_string()).collect::<Vec<_>>(),
+            [
+                "`allow_reserved` is only applicable to `Query` parameters, but parameter `id` is in `Path`",
+                "`Form` style is only applicable to `Query` and `Cookie` parameters, but parameter `size` is in `Header`"
+            ]
+        );
+
+        let lists = parse_all
+            .parse_str(r#"("id" = String, Path), (Query, "page", "size"), Filter"#)
+            .unwrap();
+        assert_eq!(
+            lists.iter().map(|list| list.0.len()).collect::<Vec<_>>(),
+            [1, 2, 1]
+        );
+    }
+
+    #[test]
+    fn parse_malformed_parameters_terminates_with_error() {
+        use syn::parse::Parser;
+        let parse_all = |input: ParseStream| ParameterList::parse_all(input);
+
+        for input in [
+            ",",
+            ",,,",
+            "=",
+            "= = =",
+            ";",
+            "\"id\"",
+            "\"id\" = i32",
+            "(),",
+            "((())), ((()))",
+            "(,,,)",
+            "(\"id\" = i32, Path,,,)",
+            "(\"id\" = , Path)",
+            "(\"id\" = i32, Path, style)",
+            "(\"id\" = i32, Path, = \"a\")",
+            "(\"id\" = i32, Path, in)",
+            "(\"id\" = i32, Path) (\"page\" = i32, Query)",
+            "(Query)",
+            "(Query,)",
+            "(Query, ,)",
+            "(Query, required_group = )",
+            "Filter Filter",
+            "Filter, , Filter",
+            "1, 2, 3",
+        ] {
+            assert!(parse_all.parse_str(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn resolve_description_refs_of_parameter_lists() {
+        use syn::parse::Parser;
+        let parse_all = |input: ParseStream| ParameterList::parse_all(input);
+
+        let mut lists = parse_all
+            .parse_str(
+                r#"("page" = i32, Query, description = "Page number"), ("offset" = i32, Query, description_template = "The {name} of items"), (Query, "next_page" = i32, "previous_offset" = i32), ("other_page" = i32, Query, description_ref = "page"), ("skip" = i32, Query, description_ref = "offset")"#,
+            )
+            .unwrap();
+        ParameterList::resolve_description_refs(&mut lists).unwrap();
+
+        let descriptions = lists
+            .iter()
+            .flat_map(|list| &list.0)
+            .filter_map(|parameter| match parameter {
+                Parameter::Value(parameter) => parameter.to_openapi_value()["description"]
+                    .as_str()
+                    .map(ToString::to_string),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            descriptions,
+            [
+                "Page number",
+                "The offset of items",
+                "Page number",
+                "The skip of items"
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_invalid_description_refs_fails() {
+        use syn::parse::Parser;
+        let parse_all = |input: ParseStream| ParameterList::parse_all(input);
+
+        let mut lists = parse_all
+            .parse_str(
+                r#"("page" = i32, Query), ("size" = i32, Query, description_ref = "page"), ("offset" = i32, Query, description_ref = "start")"#,
+            )
+            .unwrap();
+        let Err(error) = ParameterList::resolve_description_refs(&mut lists) else {
+            panic!("invalid description references should fail");
+        };
+
+        assert_eq!(
+            error.into_iter().map(|error| error.to_string()).collect::<Vec<_>>(),
+            [
+                "parameter `page` referenced by `description_ref` has no description",
+                "unknown parameter `start` in `description_ref`, expected name of a parameter defined in the same `params(...)`"
+            ]
+        );
+
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("size" = i32, Query, description = "Size", description_ref = "page")"#,
+        ) else {
+            panic!("description_ref with description should fail");
+        };
+        assert_eq!(
+            error.to_string(),
+            "`description_ref` cannot be used together with `description` or `description_template`"
+        );
+    }
+
+    #[test]
+    fn parse_custom_parameter_in_location() {
+        let parameter = syn::parse_str::<ValueParameter>(r#"("payload" = String, in = "body")"#)
+            .expect("parameter with custom location should parse");
+        assert!(parameter.parameter_in == ParameterIn::Other(String::from("body")));
+
+        let parameter = syn::parse_str::<ValueParameter>(r#"("page" = i32, in = "query")"#)
+            .expect("parameter with standard location should parse");
+        assert!(parameter.parameter_in == ParameterIn::Query);
+    }
+
+    #[test]
+    fn identically_parsed_parameters_are_equal() {
+        for input in [
+            r#"("id" = i64, Path, deprecated, description = "Pet id")"#,
+            r#"ref = "PageSize""#,
+            "PetQuery",
+        ] {
+            let parameter = syn::parse_str::<Parameter>(input).expect("parameter should parse");
+            let other = syn::parse_str::<Parameter>(input).expect("parameter should parse");
+            assert!(parameter == other, "parameters should be equal: {input}");
+            assert!(
+                parameter.clone() == parameter,
+                "clone should be equal: {input}"
+            );
+        }
+
+        let query = syn::parse_str::<Parameter>(r#"("id" = i64, Query)"#).unwrap();
+        let path = syn::parse_str::<Parameter>(r#"("id" = i64, Path)"#).unwrap();
+        assert!(query != path);
+    }
+
+    #[test]
+    fn parse_invalid_custom_parameter_in_fails() {
+        for input in [
+            r#"("id" = i32, in = "")"#,
+            r#"("id" = i32, in = "my body")"#,
+        ] {
+            let Err(error) = syn::parse_str::<ValueParameter>(input) else {
+                panic!("invalid custom location should fail: {input}");
+            };
+            assert_eq!(
+                error.to_string(),
+                r#"expected parameter location without whitespace e.g. `in = "body"`"#
+            );
+        }
+
+        let Err(error) = syn::parse_str::<ValueParameter>(r#"("id" = i32, Query, in = "body")"#)
+        else {
+            panic!("duplicate location should fail");
+        };
+        assert_eq!(error.to_string(), "`in` is already specified");
+    }
+
+    #[test]
+    fn parameter_tokens_snapshot() {
+        let parameter = syn::parse_str::<ValueParameter>(
+            r#"("id" = i32, Query, example = 1, required = true, extensions("x-a" = 1), allow_reserved, explode, style = Form, deprecated = "use key", description = "Id of item", minimum = 1)"#,
+        )
+        .unwrap();
+
+        // attributes are emitted in fixed order regardless of the definition order
+        let expected = quote! {
+            utoipa::openapi::path::ParameterBuilder::from(utoipa::openapi::path::Parameter::new("id"))
+                .parameter_in(utoipa::openapi::path::ParameterIn::Query)
+                .description(Some("Id of item"))
+                .deprecated(Some(utoipa::openapi::Deprecated::True))
+                .style(Some(utoipa::openapi::path::ParameterStyle::Form))
+                .explode(Some(true))
+                .allow_reserved(Some(true))
+                .example(Some(serde_json::json!(1)))
+                .extensions(Some(
+                    utoipa::openapi::extensions::ExtensionsBuilder::new()
+                        .add("x-deprecated-reason", "use key")
+                        .add("x-a", 1)
+                        .build()
+                ))
+                .schema(Some(
+                    utoipa::openapi::ObjectBuilder::new()
+                        .schema_type(utoipa::openapi::schema::SchemaType::new(
+                            utoipa::openapi::schema::Type::Integer
+                        ))
+                        .format(Some(utoipa::openapi::schema::SchemaFormat::KnownFormat(
+                            utoipa::openapi::schema::KnownFormat::Int32
+                        )))
+                        .minimum(Some(1))
+                ))
+                .required(utoipa::openapi::Required::True)
+                .required(utoipa::openapi::Required::True)
+        };
+
+        assert_eq!(
+            parameter.to_token_stream().to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn parameter_to_openapi_value() {
+        use serde_json::json;
+
+        let parameter = syn::parse_str::<ValueParameter>(
+            r#"("id" = Option<i64>, Query, description = "Id of item", deprecated = "use key", since = "2.3.0", style = Form, explode, allow_reserved, alias = "item_id", minimum = 1)"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parameter.to_openapi_value(),
+            json!({
+                "name": "id",
+                "in": "query",
+                "description": "Id of item",
+                "deprecated": true,
+                "style": "form",
+                "explode": true,
+                "allowReserved": true,
+                "required": false,
+                "schema": {
+                    "type": ["integer", "null"],
+                    "format": "int64"
+                },
+                "x-deprecated-reason": "use key",
+                "x-deprecated-since": "2.3.0",
+                "x-aliases": ["item_id"]
+            })
+        );
+
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("prefs" = Vec<String>, Cookie, x_http_only)"#)
+                .unwrap();
+        assert_eq!(
+            parameter.to_openapi_value(),
+            json!({
+                "name": "prefs",
+                "in": "cookie",
+                "style": "form",
+                "explode": false,
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
+                },
+                "x-http-only": true
+            })
+        );
+
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("filter" = Option<Filter>, Path)"#).unwrap();
+        assert_eq!(
+            parameter.to_openapi_value(),
+            json!({
+                "name": "filter",
+                "in": "path",
+                "required": true,
+                "schema": {
+                    "oneOf": [
+                        { "type": "null" },
+                        { "$ref": "#/components/schemas/Filter" }
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn parse_example_url() {
+        let parameter = syn::parse_str::<ValueParameter>(
+            r#"("id" = i32, Path, example_url = "https://api.example.com/items/1")"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parameter.to_openapi_value()["x-example-url"],
+            "https://api.example.com/items/1"
+        );
+
+        for input in [
+            r#"("id" = i32, Path, example_url = "/items/1")"#,
+            r#"("id" = i32, Path, example_url = "api.example.com/items/1")"#,
+            r#"("id" = i32, Path, example_url = "1https://api.example.com")"#,
+            r#"("id" = i32, Path, example_url = "https:")"#,
+            r#"("id" = i32, Path, example_url = "https://api.example.com/items/ 1")"#,
+        ] {
+            let Err(error) = syn::parse_str::<ValueParameter>(input) else {
+                panic!("example_url without scheme should fail: {input}");
+            };
+            assert_eq!(
+                error.to_string(),
+                r#"expected URL with scheme e.g. `example_url = "https://api.example.com/items/1"`"#
+            );
+        }
+    }
+
+    #[test]
+    fn parse_invalid_parameter_in_fails_at_ident() {
+        let Err(error) = syn::parse_str::<ValueParameter>(r#"("id" = i32, Quary)"#) else {
+            panic!("invalid parameter in should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "unexpected in, expected one of: Query, Path, Header, Cookie"
+        );
+        assert_eq!(error.span().start().column, 13);
+    }
+
+    #[test]
+    fn parse_description_template_expands_name() {
+        let parameter = syn::parse_str::<ValueParameter>(
+            r#"("user_id" = i32, Path, description_template = "The {name} of the {resource}")"#,
+        )
+        .unwrap();
+
+        let tokens = parameter.to_token_stream().to_string();
+        assert!(
+            tokens.contains(r#". description (Some ("The user_id of the {resource}"))"#),
+            "{tokens}"
+        );
+    }
+
+    #[test]
+    fn parse_description_template_with_description_fails() {
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("id" = i32, Path, description = "Id", description_template = "The {name}")"#,
+        ) else {
+            panic!("description_template with description should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "`description_template` cannot be used together with `description`"
+        );
+    }
+
+    #[test]
+    fn parse_duplicate_attribute_fails() {
+        let Err(error) = syn::parse_str::<ValueParameter>(
+            r#"("id" = i32, Path, description = "a", description = "b")"#,
+        ) else {
+            panic!("duplicate description should fail");
+        };
+
+        assert_eq!(error.to_string(), "`description` is already specified");
+        assert_eq!(error.span().start().column, 38);
+    }
+
+    #[test]
+    fn parse_duplicate_parameter_in_fails() {
+        let Err(error) =
+            syn::parse_str::<ValueParameter>(r#"("id" = i32, Path, deprecated, Query)"#)
+        else {
+            panic!("duplicate parameter in should fail");
+        };
+
+        assert_eq!(error.to_string(), "`in` is already specified");
+    }
+
+    #[test]
+    fn parse_multi_valued_attribute() {
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("id" = i32, Header, alias = "ID", alias = "Id")"#);
+
+        assert!(parameter.is_ok(), "{:?}", parameter.err());
+    }
+
+    #[test]
+    fn parse_allow_reserved_on_non_query_parameter_fails() {
+        let Err(error) =
+            syn::parse_str::<ValueParameter>(r#"("id" = String, Path, allow_reserved)"#)
+        else {
+            panic!("allow_reserved on path parameter should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "`allow_reserved` is only applicable to `Query` parameters, but parameter `id` is in `Path`"
+        );
+    }
+
+    #[test]
+    fn parse_allow_reserved_on_query_parameter() {
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("value" = String, Query, allow_reserved)"#)
+                .expect("allow_reserved on query parameter should parse");
+
+        assert!(matches!(
+            parameter.features.1.as_slice(),
+            [Feature::AllowReserved(_)]
+        ));
+    }
+
+    #[test]
+    fn parse_allow_empty_value_on_non_query_parameter_fails() {
+        let Err(error) =
+            syn::parse_str::<ValueParameter>(r#"("verbose" = bool, Header, allow_empty_value)"#)
+        else {
+            panic!("allow_empty_value on header parameter should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "`allow_empty_value` is only applicable to `Query` parameters, but parameter `verbose` is in `Header`"
+        );
+    }
+
+    #[test]
+    fn parse_allow_empty_value_on_query_parameter() {
+        let parameter = syn::parse_str::<ValueParameter>(
+            r#"("verbose" = Option<bool>, Query, allow_empty_value = true)"#,
+        )
+        .expect("allow_empty_value on query parameter should parse");
+
+        let tokens = parameter.try_to_token_stream().unwrap().to_string();
+        assert!(
+            tokens.contains(". allow_empty_value (Some (true))"),
+            "{tokens}"
+        );
+    }
+
+    #[test]
+    fn parse_unknown_style_fails() {
+        for (input, expected) in [
+            (
+                r#"("id" = i32, Query, style = "froom")"#,
+                "unexpected style, expected one of: matrix, label, form, simple, spaceDelimited, pipeDelimited, deepObject",
+            ),
+            (
+                r#"("id" = i32, Query, style = Froom)"#,
+                "unexpected style, expected one of: Matrix, Label, Form, Simple, SpaceDelimited, PipeDelimited, DeepObject",
+            ),
+            (
+                r#"("id" = i32, Query, style = 1)"#,
+                "unexpected style, expected one of: Matrix, Label, Form, Simple, SpaceDelimited, PipeDelimited, DeepObject",
+            ),
+        ] {
+            let Err(error) = syn::parse_str::<ValueParameter>(input) else {
+                panic!("unknown style should fail: {input}");
+            };
+            assert_eq!(error.to_string(), expected);
+            // error points to the style value
+            assert_eq!(error.span().start().column, 28, "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_matrix_style_on_non_path_parameter_fails() {
+        let Err(error) =
+            syn::parse_str::<ValueParameter>(r#"("id" = String, Query, style = Matrix)"#)
+        else {
+            panic!("matrix style on query parameter should fail");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "`Matrix` and `Label` styles are only applicable to `Path` parameters, but parameter `id` is in `Query`"
+        );
+    }
+
+    #[test]
+    fn parse_label_style_on_path_parameter() {
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("id" = String, Path, style = "label")"#)
+                .expect("label style on path parameter should parse");
+
+        assert!(matches!(
+            parameter.features.1.as_slice(),
+            [Feature::Style(_)]
+        ));
+    }
+
+    #[test]
+    fn parameter_numeric_validation_on_non_number_type_fails() {
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("page" = String, Query, minimum = 1)"#).unwrap();
+
+        let Err(diagnostics) = parameter.try_to_token_stream() else {
+            panic!("minimum on string parameter should fail");
+        };
+
+        assert_eq!(
+            diagnostics.message(),
+            "`minimum` error: can only be used with `number` type"
+        );
+    }
+
+    #[test]
+    fn parameter_string_validation_on_non_string_type_fails() {
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("id" = i64, Path, max_length = 10)"#).unwrap();
+
+        let Err(diagnostics) = parameter.try_to_token_stream() else {
+            panic!("max_length on integer parameter should fail");
+        };
+
+        assert_eq!(
+            diagnostics.message(),
+            "`max_length` error: can only be used with `string` type"
+        );
+    }
+
+    #[test]
+    fn parameter_numeric_validation_on_number_type() {
+        let parameter = syn::parse_str::<ValueParameter>(
+            r#"("page" = i32, Query, minimum = 1, maximum = 100, multiple_of = 2)"#,
+        )
+        .unwrap();
+
+        assert!(parameter.try_to_token_stream().is_ok());
+    }
+
+    #[test]
+    fn parameter_array_validation_is_validated_against_array_and_items() {
+        let parameter = syn::parse_str::<ValueParameter>(
+            r#"("ids" = Option<Vec<i32>>, Query, minimum = 1, max_items = 10, unique_items)"#,
+        )
+        .unwrap();
+        assert!(parameter.try_to_token_stream().is_ok());
+
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("ids" = Vec<String>, Query, minimum = 1)"#)
+                .unwrap();
+        let Err(diagnostics) = parameter.try_to_token_stream() else {
+            panic!("minimum on array of strings parameter should fail");
+        };
+        assert_eq!(
+            diagnostics.message(),
+            "`minimum` error: can only be used with `number` type"
+        );
+    }
+
+    #[test]
+    fn parameter_number_format_on_non_number_type_fails() {
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("name" = String, Query, format = "int64")"#)
+                .unwrap();
+
+        let Err(diagnostics) = parameter.try_to_token_stream() else {
+            panic!("int64 format on string parameter should fail");
+        };
+
+        assert_eq!(
+            diagnostics.message(),
+            "`format` error: can only be used with `number` type"
+        );
+    }
+
+    #[test]
+    fn parameter_string_format_on_non_string_type_fails() {
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("id" = Option<i64>, Path, format = DateTime)"#)
+                .unwrap();
+
+        let Err(diagnostics) = parameter.try_to_token_stream() else {
+            panic!("date-time format on integer parameter should fail");
+        };
+
+        assert_eq!(
+            diagnostics.message(),
+            "`format` error: can only be used with `string` type"
+        );
+    }
+
+    #[test]
+    fn parameter_enum_values_incompatible_with_type_fails() {
+        for (input, message) in [
+            (
+                r#"("count" = i32, Query, enum_values = [1, "b"])"#,
+                "`enum_values` error: string value can only be used with `string` type",
+            ),
+            (
+                r#"("order" = Option<String>, Query, enum_values = ["asc", 1])"#,
+                "`enum_values` error: integer value can only be used with `integer` or `number` type",
+            ),
+            (
+                r#"("ids" = Vec<u64>, Query, enum_values = [1.5])"#,
+                "`enum_values` error: float value can only be used with `number` type",
+            ),
+            (
+                r#"("flag" = bool, Query, enum_values = ["yes"])"#,
+                "`enum_values` error: string value can only be used with `string` type",
+            ),
+        ] {
+            let parameter = syn::parse_str::<ValueParameter>(input).unwrap();
+
+            let Err(diagnostics) = parameter.try_to_token_stream() else {
+                panic!("incompatible enum values should fail: {input}");
+            };
+
+            assert_eq!(diagnostics.message(), message, "{input}");
+        }
+    }
+
+    #[test]
+    fn parameter_enum_values_compatible_with_type() {
+        for input in [
+            r#"("count" = i32, Query, enum_values = [1, -2])"#,
+            r#"("ratio" = f64, Query, enum_values = [1, 1.5])"#,
+            r#"("flag" = Option<bool>, Query, enum_values = [true])"#,
+            r#"("sort" = Vec<String>, Query, enum_values = ["asc", "desc"])"#,
+            r#"("filter" = Filter, Query, enum_values = ["a", 1])"#,
+        ] {
+            let parameter = syn::parse_str::<ValueParameter>(input).unwrap();
+
+            assert!(parameter.try_to_token_stream().is_ok(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parameter_numeric_bounds_on_date_format_fails() {
+        for (input, attribute) in [
+            (
+                r#"("since" = String, Query, format = Date, minimum = 1)"#,
+                "minimum",
+            ),
+            (
+                r#"("until" = String, Query, format = "date-time", exclusive_maximum = 10)"#,
+                "exclusive_maximum",
+            ),
+            (
+                r#"("until", Query, schema_type = "string", format = DateTime, maximum = 10)"#,
+                "maximum",
+            ),
+        ] {
+            let parameter = syn::parse_str::<ValueParameter>(input).unwrap();
+
+            let Err(diagnostics) = parameter.try_to_token_stream() else {
+                panic!("numeric bound on date format should fail: {input}");
+            };
+
+            assert_eq!(
+                diagnostics.message(),
+                format!("`{attribute}` error: cannot be used with `date` or `date-time` format, use `pattern` to restrict the value instead")
+            );
+        }
+    }
+
+    #[test]
+    fn parameter_format_on_matching_type() {
+        for input in [
+            r#"("price" = f64, Query, format = "double")"#,
+            r#"("id" = i64, Path, format = Int32)"#,
+            r#"("since" = String, Query, format = "date-time")"#,
+            r#"("code" = i32, Query, format = "my-custom-format")"#,
+        ] {
+            let parameter = syn::parse_str::<ValueParameter>(input).unwrap();
+            assert!(parameter.try_to_token_stream().is_ok(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parameter_array_validation_on_non_array_type_fails() {
+        let parameter =
+            syn::parse_str::<ValueParameter>(r#"("id" = i32, Query, unique_items)"#).unwrap();
+
+        let Err(diagnostics) = parameter.try_to_token_stream() else {
+            panic!("unique_items on integer parameter should fail");
+        };
+
+        assert_eq!(
+            diagnostics.message(),
+            "`unique_items` error: can only be used with `Vec`, `array` or `slice` types"
+        );
+    }
+}
@@ -7,6 +7,7 @@ use crate::{Diagnostics, ToTokensDiagnostics};
 
 /// Represents data type of [`Schema`].
 #[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy)]
 #[allow(dead_code)]
 pub enum SchemaTypeInner {
     /// Generic schema type allows "properties" with custom types
@@ -166,18 +167,44 @@ impl SchemaType<'_> {
     pub fn is_number(&self) -> bool {
         match &*self.last_segment_to_string() {
             "f32" | "f64" => true,
+            #[cfg(feature = "decimal_float")]
+            "Decimal" => true,
             _ if self.is_integer() => true,
             _ => false,
         }
     }
 
+    /// Check whether type is tokenized as `string` schema type.
     pub fn is_string(&self) -> bool {
-        matches!(&*self.last_segment_to_string(), "str" | "String")
+        match &*self.last_segment_to_string() {
+            "str" | "String" | "char" => true,
+            #[cfg(feature = "chrono")]
+            "DateTime" | "NaiveDateTime" | "NaiveDate" | "NaiveTime" => true,
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            "Date" | "Duration" => true,
+            #[cfg(feature = "decimal")]
+            "Decimal" => true,
+            #[cfg(feature = "rocket_extras")]
+            "PathBuf" => true,
+            #[cfg(feature = "uuid")]
+            "Uuid" => true,
+            #[cfg(feature = "ulid")]
+            "Ulid" => true,
+            #[cfg(feature = "url")]
+            "Url" => true,
+            #[cfg(feature = "time")]
+            "PrimitiveDateTime" | "OffsetDateTime" => true,
+            _ => false,
+        }
     }
 
     pub fn is_byte(&self) -> bool {
         matches!(&*self.last_segment_to_string(), "u8")
     }
+
+    pub fn is_boolean(&self) -> bool {
+        matches!(&*self.last_segment_to_string(), "bool")
+    }
 }
 
 #[inline]
@@ -426,6 +453,77 @@ impl KnownFormat {
         !matches!(self, Self::Unknown)
     }
 
+    /// Check whether format is only meaningful for `integer` and `number` types.
+    ///
+    /// [`KnownFormat::Custom`] formats are recognized by their OpenAPI name e.g. `"int64"`.
+    pub fn is_number_format(&self) -> bool {
+        match self {
+            #[cfg(feature = "non_strict_integers")]
+            Self::Int8 | Self::Int16 | Self::UInt8 | Self::UInt16 | Self::UInt32 | Self::UInt64 => {
+                true
+            }
+            Self::Int32 | Self::Int64 | Self::Float | Self::Double => true,
+            Self::Custom(format) => matches!(
+                &**format,
+                "int8"
+                    | "int16"
+                    | "int32"
+                    | "int64"
+                    | "uint8"
+                    | "uint16"
+                    | "uint32"
+                    | "uint64"
+                    | "float"
+                    | "double"
+            ),
+            _ => false,
+        }
+    }
+
+    /// Check whether format is `date` or `date-time` string format.
+    pub fn is_date_format(&self) -> bool {
+        match self {
+            Self::Date | Self::DateTime => true,
+            Self::Custom(format) => matches!(&**format, "date" | "date-time"),
+            _ => false,
+        }
+    }
+
+    /// Check whether format is only meaningful for `string` types.
+    ///
+    /// [`KnownFormat::Custom`] formats are recognized by their OpenAPI name e.g. `"date-time"`.
+    pub fn is_string_format(&self) -> bool {
+        match self {
+            Self::Custom(format) => matches!(
+                &**format,
+                "byte"
+                    | "binary"
+                    | "date"
+                    | "date-time"
+                    | "duration"
+                    | "password"
+                    | "uuid"
+                    | "ulid"
+                    | "uri"
+                    | "uri-reference"
+                    | "iri"
+                    | "iri-reference"
+                    | "email"
+                    | "idn-email"
+                    | "hostname"
+                    | "idn-hostname"
+                    | "ipv4"
+                    | "ipv6"
+                    | "uri-template"
+                    | "json-pointer"
+                    | "relative-json-pointer"
+                    | "regex"
+            ),
+            Self::Unknown => false,
+            _ => !self.is_number_format(),
+        }
+    }
+
     fn get_allowed_formats() -> String {
         let default_formats = [
             "Int32",
@@ -178,6 +178,43 @@ fn derive_path_with_all_info_success() {
     }
 }
 
+#[test]
+fn derive_path_with_deprecated_note() {
+    mod deprecated_note {
+        #[utoipa::path(get, path = "/foo", responses((status = 200, description = "success")))]
+        #[deprecated(since = "1.2.0", note = "Use `/bar` instead")]
+        #[allow(unused)]
+        async fn foo() {}
+
+        #[utoipa::path(get, path = "/bar", responses((status = 200, description = "success")))]
+        #[deprecated = "Use `/baz` instead"]
+        #[allow(unused)]
+        async fn bar() {}
+    }
+
+    #[allow(deprecated)]
+    let foo = test_api_fn_doc! {
+        deprecated_note::foo,
+        operation: get,
+        path: "/foo"
+    };
+    #[allow(deprecated)]
+    let bar = test_api_fn_doc! {
+        deprecated_note::bar,
+        operation: get,
+        path: "/bar"
+    };
+
+    assert_value! {foo=>
+       "deprecated" = r#"true"#, "Api fn deprecated status"
+       "x-deprecated-reason" = r#""Use `/bar` instead""#, "Api fn deprecated reason"
+    }
+    assert_value! {bar=>
+       "deprecated" = r#"true"#, "Api fn deprecated status"
+       "x-deprecated-reason" = r#""Use `/baz` instead""#, "Api fn deprecated reason"
+    }
+}
+
 #[test]
 fn derive_path_with_defaults_success() {
     test_api_fn! {
@@ -768,6 +805,53 @@ fn derive_path_query_params_with_schema_features() {
     }
 }
 
+#[test]
+fn derive_path_query_params_with_enum_values() {
+    let operation = api_fn_doc_with_params! {get: "/foo" =>
+        #[into_params(parameter_in = Query)]
+        struct MyParams {
+            #[param(enum_values = ["asc", "desc"])]
+            sort: String,
+        }
+    };
+    let parameters = operation.get("parameters").unwrap();
+
+    assert_json_eq! {
+        parameters,
+        json!{[
+            {
+            "in": "query",
+            "name": "sort",
+            "required": true,
+            "schema": {
+                "type": "string",
+                "enum": ["asc", "desc"]
+            }
+          }
+        ]}
+    }
+}
+
+#[test]
+fn derive_path_query_params_description_from_doc_comments_or_attribute() {
+    let operation = api_fn_doc_with_params! {get: "/foo" =>
+        #[into_params(parameter_in = Query)]
+        struct MyParams {
+            /// Number of items per page.
+            page_size: i64,
+            /// Internal note that is overridden.
+            #[param(description = "Page number starting from 1.")]
+            page: i64,
+        }
+    };
+    let parameters = operation.get("parameters").unwrap();
+
+    assert_value! {parameters=>
+        "[0].description" = r#""Number of items per page.""#, "Parameter description from doc comment"
+        "[1].description" = r#""Page number starting from 1.""#, "Parameter description from attribute"
+    };
+}
+
 #[test]
 fn derive_path_params_always_required() {
     let operation = api_fn_doc_with_params! {get: "/foo" =>
@@ -1837,6 +1921,44 @@ fn derive_into_params_with_serde_skip() {
     )
 }
 
+#[test]
+fn derive_into_params_with_doc_hidden_field() {
+    #[derive(IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Params {
+        name: String,
+        /// Internal paging token.
+        #[doc(hidden)]
+        token: Option<String>,
+    }
+
+    #[utoipa::path(get, path = "/params", params(Params))]
+    #[allow(unused)]
+    fn get_params() {}
+    let operation = test_api_fn_doc! {
+        get_params,
+        operation: get,
+        path: "/params"
+    };
+
+    let value = operation.pointer("/parameters");
+
+    assert_json_eq!(
+        value,
+        json!([
+          {
+              "in": "query",
+              "name": "name",
+              "required": true,
+              "schema": {
+                  "type": "string",
+              },
+          },
+        ])
+    )
+}
+
 // TODO: IntoParams seems not to follow Option<T> is automatically nullable rule!
 
 #[test]
@@ -2944,6 +3066,106 @@ fn derive_into_params_with_ignored_eq_false_field() {
     )
 }
 
+#[test]
+fn derive_into_params_with_no_description() {
+    #![allow(unused)]
+
+    #[derive(IntoParams)]
+    #[into_params(parameter_in = Query)]
+    struct Params {
+        /// Internal notes of the name.
+        #[param(no_description)]
+        name: String,
+        /// Id of the item.
+        #[param(no_description = false)]
+        id: i32,
+    }
+
+    #[utoipa::path(get, path = "/params", params(Params))]
+    #[allow(unused)]
+    fn get_params() {}
+    let operation = test_api_fn_doc! {
+        get_params,
+        operation: get,
+        path: "/params"
+    };
+
+    let value = operation.pointer("/parameters");
+
+    assert_json_eq!(
+        value,
+        json!([
+            {
+                "in": "query",
+                "name": "name",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "in": "query",
+                "name": "id",
+                "description": "Id of the item.",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            }
+        ])
+    )
+}
+
+#[test]
+fn derive_into_params_with_blank_doc_comment_skips_description() {
+    #![allow(unused)]
+
+    #[derive(IntoParams)]
+    #[into_params(parameter_in = Query)]
+    struct Params {
+        ///
+        ///
+        name: String,
+        ///    
+        id: i32,
+    }
+
+    #[utoipa::path(get, path = "/params", params(Params))]
+    #[allow(unused)]
+    fn get_params() {}
+    let operation = test_api_fn_doc! {
+        get_params,
+        operation: get,
+        path: "/params"
+    };
+
+    let value = operation.pointer("/parameters");
+
+    assert_json_eq!(
+        value,
+        json!([
+            {
+                "in": "query",
+                "name": "name",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "in": "query",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            }
+        ])
+    )
+}
+
 #[test]
 fn derive_octet_stream_request_body() {
     #![allow(dead_code)]
@@ -3167,3 +3389,111 @@ fn derive_lifetime_generic_request_body_compiles() {
     let _ = serde_json::to_value(__path_test_const_generic::operation())
         .expect("Operation is JSON serializable");
 }
+
+#[test]
+fn derive_into_params_with_deprecated_note() {
+    #![allow(unused, deprecated)]
+
+    #[derive(IntoParams)]
+    #[into_params(parameter_in = Query)]
+    struct Params {
+        #[deprecated(since = "1.2.0", note = "Use `name` instead")]
+        old_name: String,
+        #[deprecated = "Use `id` instead"]
+        old_id: i32,
+        #[deprecated]
+        legacy: bool,
+    }
+
+    #[utoipa::path(get, path = "/params", params(Params))]
+    #[allow(unused)]
+    fn get_params() {}
+    let operation = test_api_fn_doc! {
+        get_params,
+        operation: get,
+        path: "/params"
+    };
+
+    let value = operation.pointer("/parameters");
+
+    assert_json_eq!(
+        value,
+        json!([
+            {
+                "in": "query",
+                "name": "old_name",
+                "deprecated": true,
+                "x-deprecated-reason": "Use `name` instead",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "in": "query",
+                "name": "old_id",
+                "deprecated": true,
+                "x-deprecated-reason": "Use `id` instead",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            },
+            {
+                "in": "query",
+                "name": "legacy",
+                "deprecated": true,
+                "required": true,
+                "schema": {
+                    "type": "boolean"
+                }
+            }
+        ])
+    )
+}
+
+#[cfg(feature = "localized_docs")]
+#[test]
+fn derive_into_params_with_localized_docs() {
+    #![allow(unused)]
+
+    #[derive(IntoParams)]
+    #[into_params(parameter_in = Query)]
+    struct Params {
+        /// Name of the item.
+        #[utoipa_doc(locale = "de", text = "Name des Elements.")]
+        #[utoipa_doc(locale = "fi", text = "Kohteen nimi.")]
+        name: String,
+    }
+
+    #[utoipa::path(get, path = "/params", params(Params))]
+    #[allow(unused)]
+    fn get_params() {}
+    let operation = test_api_fn_doc! {
+        get_params,
+        operation: get,
+        path: "/params"
+    };
+
+    let value = operation.pointer("/parameters");
+
+    assert_json_eq!(
+        value,
+        json!([
+            {
+                "in": "query",
+                "name": "name",
+                "description": "Name of the item.",
+                "x-translations": {
+                    "de": "Name des Elements.",
+                    "fi": "Kohteen nimi."
+                },
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            }
+        ])
+    )
+}
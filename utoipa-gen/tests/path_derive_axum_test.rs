@@ -632,7 +632,7 @@ fn derive_path_with_validation_attributes_axum() {
 
     #[utoipa::path(
         get,
-        path = "foo/{foo_id}",
+        path = "foo/{foo_id}/{name}/{nonnullable}",
         responses(
             (status = 200, description = "success response")
         ),
@@ -646,14 +646,16 @@ fn derive_path_with_validation_attributes_axum() {
         )
     )]
     #[allow(unused)]
-    async fn get_foo(path: Path<String>, query: Query<Params>) {}
+    async fn get_foo(path: Path<(String, Option<String>, String)>, query: Query<Params>) {}
 
     #[derive(OpenApi, Default)]
     #[openapi(paths(get_foo))]
     struct ApiDoc;
 
     let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
-    let parameters = doc.pointer("/paths/foo~1{foo_id}/get/parameters").unwrap();
+    let parameters = doc
+        .pointer("/paths/foo~1{foo_id}~1{name}~1{nonnullable}/get/parameters")
+        .unwrap();
 
     let config = Config::new(CompareMode::Strict).numeric_mode(NumericMode::AssumeFloat);
 
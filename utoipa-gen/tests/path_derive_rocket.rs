@@ -233,14 +233,14 @@ fn resolve_get_path_with_anonymous_parts() {
         "[0].deprecated" = r#"null"#, "Id parameter required"
         "[0].in" = r#""path""#, "Id parameter in"
 
-        "[1].schema.type" = r#"null"#, "Arg0 parameter type"
+        "[1].schema.type" = r#""string""#, "Arg0 parameter type"
         "[1].schema.format" = r#"null"#, "Arg0 parameter format"
         "[1].name" = r#""arg0""#, "Arg0 parameter name"
         "[1].required" = r#"true"#, "Arg0 parameter required"
         "[1].deprecated" = r#"null"#, "Arg0 parameter required"
         "[1].in" = r#""path""#, "Arg0 parameter in"
 
-        "[2].schema.type" = r#"null"#, "Arg1 parameter type"
+        "[2].schema.type" = r#""string""#, "Arg1 parameter type"
         "[2].schema.format" = r#"null"#, "Arg1 parameter format"
         "[2].name" = r#""arg1""#, "Arg1 parameter name"
         "[2].required" = r#"true"#, "Arg1 parameter required"
@@ -448,3 +448,2122 @@ fn derive_into_params_required_custom_query_parameter_required() {
         ])
     );
 }
+
+#[test]
+fn derive_path_params_with_style_as_str() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("tags" = Vec<String>, Query, style = "pipeDelimited", explode = false),
+            ("filter" = Vec<String>, Query, style = "form", explode)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() {}
+
+    let operation = serde_json::to_value(__path_get_foo::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "tags",
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
+                },
+                "style": "pipeDelimited",
+                "explode": false
+            },
+            {
+                "in": "query",
+                "name": "filter",
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
+                },
+                "style": "form",
+                "explode": true
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_literal_examples() {
+    #[utoipa::path(
+        get,
+        path = "/users/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = i64, Path, example = 123),
+            ("name" = String, Query, example = "John"),
+            ("active" = bool, Query, example = true),
+            ("tags" = Vec<String>, Query, example = json!(["admin", "user"]))
+        )
+    )]
+    #[allow(unused)]
+    async fn get_user() {}
+
+    let operation = serde_json::to_value(__path_get_user::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    common::assert_json_array_len(parameters, 4);
+    assert_value! {parameters=>
+        "[0].example" = r#"123"#, "Parameter integer example"
+        "[1].example" = r#""John""#, "Parameter string example"
+        "[2].example" = r#"true"#, "Parameter bool example"
+        "[3].example" = r#"["admin","user"]"#, "Parameter json example"
+    };
+}
+
+#[test]
+fn derive_path_params_with_explicit_required() {
+    #[utoipa::path(
+        get,
+        path = "/foo/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = i32, Path, required = false),
+            ("token" = Option<String>, Header, required),
+            ("limit" = Option<i32>, Query, required = true),
+            ("offset" = i32, Query, required = false),
+            ("page" = Option<i32>, Query)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() {}
+
+    let operation = serde_json::to_value(__path_get_foo::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    common::assert_json_array_len(parameters, 5);
+    assert_value! {parameters=>
+        "[0].required" = r#"true"#, "Path parameter is always required"
+        "[1].required" = r#"true"#, "Option header with required"
+        "[2].required" = r#"true"#, "Option query with required = true"
+        "[3].required" = r#"false"#, "Query with required = false"
+        "[4].required" = r#"false"#, "Option query without required"
+    };
+}
+
+#[test]
+fn derive_path_params_with_deprecated_reason() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("offset" = Option<i64>, Query, deprecated = "use cursor instead"),
+            ("cursor" = Option<String>, Query, deprecated = false),
+            ("page" = Option<i64>, Query, deprecated)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "deprecated": true,
+                "in": "query",
+                "name": "offset",
+                "required": false,
+                "schema": {
+                    "format": "int64",
+                    "type": ["integer", "null"]
+                },
+                "x-deprecated-reason": "use cursor instead"
+            },
+            {
+                "deprecated": false,
+                "in": "query",
+                "name": "cursor",
+                "required": false,
+                "schema": {
+                    "type": ["string", "null"]
+                }
+            },
+            {
+                "deprecated": true,
+                "in": "query",
+                "name": "page",
+                "required": false,
+                "schema": {
+                    "format": "int64",
+                    "type": ["integer", "null"]
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_default_values() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("limit" = i32, Query, default = 20),
+            ("order" = Option<String>, Query, default = "asc"),
+            ("active" = Option<bool>, Query, default = true)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "limit",
+                "required": true,
+                "schema": {
+                    "default": 20,
+                    "format": "int32",
+                    "type": "integer"
+                }
+            },
+            {
+                "in": "query",
+                "name": "order",
+                "required": false,
+                "schema": {
+                    "default": "asc",
+                    "type": ["string", "null"]
+                }
+            },
+            {
+                "in": "query",
+                "name": "active",
+                "required": false,
+                "schema": {
+                    "default": true,
+                    "type": ["boolean", "null"]
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_content_type() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Filter {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("filter" = Filter, Query, content_type = "application/json")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "filter",
+                "required": true,
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/Filter"
+                        }
+                    }
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_slice_vec_and_reference_types() {
+    #[utoipa::path(
+        get,
+        path = "/items/{name}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("ids" = [u64], Query),
+            ("tags" = Vec<&str>, Query),
+            ("name" = &str, Path)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "ids",
+                "required": true,
+                "schema": {
+                    "items": {
+                        "format": "int64",
+                        "minimum": 0,
+                        "type": "integer"
+                    },
+                    "type": "array"
+                }
+            },
+            {
+                "in": "query",
+                "name": "tags",
+                "required": true,
+                "schema": {
+                    "items": {
+                        "type": "string"
+                    },
+                    "type": "array"
+                }
+            },
+            {
+                "in": "path",
+                "name": "name",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_aliases() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("X-Request-Id" = String, Header, alias = "x-request-id", alias = "X-REQUEST-ID", deprecated = "use traceparent")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "header",
+                "name": "X-Request-Id",
+                "required": true,
+                "deprecated": true,
+                "schema": {
+                    "type": "string"
+                },
+                "x-aliases": ["x-request-id", "X-REQUEST-ID"],
+                "x-deprecated-reason": "use traceparent"
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_nullable_independent_of_option() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("name" = String, Query, nullable),
+            ("size" = Option<i32>, Query, nullable = false),
+            ("cursor" = Option<String>, Query, nullable, required)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "name",
+                "required": true,
+                "schema": {
+                    "type": ["string", "null"]
+                }
+            },
+            {
+                "in": "query",
+                "name": "size",
+                "required": false,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer"
+                }
+            },
+            {
+                "in": "query",
+                "name": "cursor",
+                "required": true,
+                "schema": {
+                    "type": ["string", "null"]
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_without_type_default_to_string_schema() {
+    #[utoipa::path(
+        get,
+        path = "/items/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id", Path),
+            ("token", Header, no_schema)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "in": "header",
+                "name": "token",
+                "required": true
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_grouped_parameter_in() {
+    #[utoipa::path(
+        get,
+        path = "/items/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = i32, Path),
+            (Query, "page" = i32, "size" = Option<i32>, "sort"),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer"
+                }
+            },
+            {
+                "in": "query",
+                "name": "page",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer"
+                }
+            },
+            {
+                "in": "query",
+                "name": "size",
+                "required": false,
+                "schema": {
+                    "format": "int32",
+                    "type": ["integer", "null"]
+                }
+            },
+            {
+                "in": "query",
+                "name": "sort",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_array_validation_attributes() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("ids" = Vec<i32>, Query, min_items = 1, max_items = 50, unique_items)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "ids",
+                "required": true,
+                "schema": {
+                    "items": {
+                        "format": "int32",
+                        "type": "integer"
+                    },
+                    "maxItems": 50,
+                    "minItems": 1,
+                    "type": "array",
+                    "uniqueItems": true
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_string_validation_attributes() {
+    #[utoipa::path(
+        get,
+        path = "/posts/{slug}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("slug" = String, Path, pattern = "^[a-z0-9-]+$", min_length = 3, max_length = 64)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_post() {}
+
+    let operation = serde_json::to_value(__path_get_post::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "slug",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "pattern": "^[a-z0-9-]+$",
+                    "minLength": 3,
+                    "maxLength": 64
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_enum_values() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("sort" = String, Query, enum_values = ["asc", "desc"]),
+            ("level" = i32, Query, enum_values = [-1, 0, 1]),
+            ("tags" = Vec<String>, Query, enum_values = ["red", "green"]),
+            ("filter" = String, Query, enum_values = [])
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "sort",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "enum": ["asc", "desc"]
+                }
+            },
+            {
+                "in": "query",
+                "name": "level",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32",
+                    "enum": [-1, 0, 1]
+                }
+            },
+            {
+                "in": "query",
+                "name": "tags",
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "enum": ["red", "green"]
+                    }
+                }
+            },
+            {
+                "in": "query",
+                "name": "filter",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_number_format() {
+    #[utoipa::path(
+        get,
+        path = "/items/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = i64, Path, format = "int64"),
+            ("price" = f64, Query, format = Double),
+            ("count" = i64, Query, format = Int32)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int64"
+                }
+            },
+            {
+                "in": "query",
+                "name": "price",
+                "required": true,
+                "schema": {
+                    "type": "number",
+                    "format": "double"
+                }
+            },
+            {
+                "in": "query",
+                "name": "count",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_include_str_description() {
+    #[utoipa::path(
+        get,
+        path = "/items/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = i64, Path, description = include_str!("./testdata/description_override"))
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "description": "This is description from include_str!\n",
+                "schema": {
+                    "type": "integer",
+                    "format": "int64"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_parameter_ref() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ref = "Page",
+            ("q" = String, Query),
+            ref = "PageSize"
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "$ref": "#/components/parameters/Page"
+            },
+            {
+                "in": "query",
+                "name": "q",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "$ref": "#/components/parameters/PageSize"
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_named_examples() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("status" = String, Query, examples(
+                ("active" = (summary = "Active items", value = json!("active"))),
+                ("archived" = (external_value = "https://example.com/archived.json"))
+            ))
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "status",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                },
+                "examples": {
+                    "active": {
+                        "summary": "Active items",
+                        "value": "active"
+                    },
+                    "archived": {
+                        "externalValue": "https://example.com/archived.json"
+                    }
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_read_only_and_write_only() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = i64, Query, read_only),
+            ("token" = String, Query, write_only)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int64",
+                    "readOnly": true
+                }
+            },
+            {
+                "in": "query",
+                "name": "token",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "writeOnly": true
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_xml() {
+    #[utoipa::path(
+        get,
+        path = "/items/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = String, Path, xml(name = "Id", namespace = "https://example.com/items")),
+            ("tags" = Vec<String>, Query, xml(wrapped(name = "tags"), name = "tag"))
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "xml": {
+                        "name": "Id",
+                        "namespace": "https://example.com/items"
+                    }
+                }
+            },
+            {
+                "in": "query",
+                "name": "tags",
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "xml": {
+                            "name": "tag"
+                        }
+                    },
+                    "xml": {
+                        "name": "tags",
+                        "wrapped": true
+                    }
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_deep_object_style() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Filter {
+        name: String,
+        active: bool,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("filter" = Filter, Query, style = "deepObject", explode)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "filter",
+                "required": true,
+                "style": "deepObject",
+                "explode": true,
+                "schema": {
+                    "$ref": "#/components/schemas/Filter"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_schema_type_override() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Token(String);
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("token" = Token, Header, schema_type = "string", min_length = 8),
+            ("previous" = Option<Token>, Query, schema_type = "string"),
+            ("active", Query, schema_type = "boolean")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "header",
+                "name": "token",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "minLength": 8
+                }
+            },
+            {
+                "in": "query",
+                "name": "previous",
+                "required": false,
+                "schema": {
+                    "type": ["string", "null"]
+                }
+            },
+            {
+                "in": "query",
+                "name": "active",
+                "required": true,
+                "schema": {
+                    "type": "boolean"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_boolean_shorthand() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("active", Query, boolean, description = "Only active items"),
+            ("archived" = Option<String>, Query, boolean)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "active",
+                "description": "Only active items",
+                "required": true,
+                "schema": {
+                    "type": "boolean"
+                }
+            },
+            {
+                "in": "query",
+                "name": "archived",
+                "required": false,
+                "schema": {
+                    "type": ["boolean", "null"]
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_const() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("X-API-Version" = String, Header, const = "2"),
+            ("limit" = i32, Query, const = 10)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "header",
+                "name": "X-API-Version",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "const": "2"
+                }
+            },
+            {
+                "in": "query",
+                "name": "limit",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32",
+                    "const": 10
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_optional_and_required_arrays() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("ids" = Option<Vec<i64>>, Query),
+            ("tags" = Vec<String>, Query),
+            ("names" = Vec<Option<String>>, Query)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "ids",
+                "required": false,
+                "schema": {
+                    "type": ["array", "null"],
+                    "items": {
+                        "type": "integer",
+                        "format": "int64"
+                    }
+                }
+            },
+            {
+                "in": "query",
+                "name": "tags",
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
+                }
+            },
+            {
+                "in": "query",
+                "name": "names",
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": ["string", "null"]
+                    }
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_extensions() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            (
+                "limit" = i32,
+                Query,
+                alias = "max",
+                extensions("x-gateway" = "internal", "x-rate-limit" = 100, "x-cached" = true, "x-weight" = 0.5)
+            )
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "limit",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                },
+                "x-aliases": ["max"],
+                "x-gateway": "internal",
+                "x-rate-limit": 100,
+                "x-cached": true,
+                "x-weight": 0.5
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_rename() {
+    #[utoipa::path(
+        get,
+        path = "/items/{itemId}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("item_id" = i32, Path, rename = "itemId"),
+            ("page_size" = Option<i32>, Query, rename = "pageSize")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "itemId",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            },
+            {
+                "in": "query",
+                "name": "pageSize",
+                "required": false,
+                "schema": {
+                    "type": ["integer", "null"],
+                    "format": "int32"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_content() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Filter {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("filter", Query, content = { "application/json": inline(Filter) })
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "filter",
+                "required": true,
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "object",
+                            "properties": {
+                                "name": {
+                                    "type": "string"
+                                }
+                            },
+                            "required": ["name"]
+                        }
+                    }
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_schema_ref() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("status" = Option<String>, Query, schema_ref = "Status"),
+            ("kind", Query, schema_ref = "ItemKind")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "status",
+                "required": false,
+                "schema": {
+                    "$ref": "#/components/schemas/Status"
+                }
+            },
+            {
+                "in": "query",
+                "name": "kind",
+                "required": true,
+                "schema": {
+                    "$ref": "#/components/schemas/ItemKind"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_required_group() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            (Query, required_group = "dates", "from" = Option<String>, "to" = Option<String>),
+            ("limit" = i32, Query)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "from",
+                "required": false,
+                "schema": {
+                    "type": ["string", "null"]
+                },
+                "x-required-group": "dates"
+            },
+            {
+                "in": "query",
+                "name": "to",
+                "required": false,
+                "schema": {
+                    "type": ["string", "null"]
+                },
+                "x-required-group": "dates"
+            },
+            {
+                "in": "query",
+                "name": "limit",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_numeric_example_kind() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("price" = f64, Query, example = 1.0),
+            ("quantity" = i32, Query, example = 1)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+
+    let price = operation.pointer("/parameters/0/example").unwrap();
+    assert!(price.is_f64(), "price example should be float: {price}");
+    assert_eq!(price.to_string(), "1.0");
+
+    let quantity = operation.pointer("/parameters/1/example").unwrap();
+    assert!(
+        quantity.is_i64(),
+        "quantity example should be integer: {quantity}"
+    );
+    assert_eq!(quantity.to_string(), "1");
+}
+
+mod models {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    pub struct Id(pub i64);
+}
+
+#[test]
+fn derive_path_params_with_module_path_type() {
+    #[utoipa::path(
+        get,
+        path = "/items/{id}/{other_id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = crate::models::Id, Path),
+            ("other_id" = self::models::Id, Path)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "$ref": "#/components/schemas/Id"
+                }
+            },
+            {
+                "in": "path",
+                "name": "other_id",
+                "required": true,
+                "schema": {
+                    "$ref": "#/components/schemas/Id"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_deprecated_since() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("page" = i64, Query, deprecated, since = "2.3.0"),
+            ("offset" = i64, Query, deprecated = "use cursor instead", since = "2.4.0")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "deprecated": true,
+                "in": "query",
+                "name": "page",
+                "required": true,
+                "schema": {
+                    "format": "int64",
+                    "type": "integer"
+                },
+                "x-deprecated-since": "2.3.0"
+            },
+            {
+                "deprecated": true,
+                "in": "query",
+                "name": "offset",
+                "required": true,
+                "schema": {
+                    "format": "int64",
+                    "type": "integer"
+                },
+                "x-deprecated-reason": "use cursor instead",
+                "x-deprecated-since": "2.4.0"
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_exploded_form_object() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Filter {
+        color: String,
+        size: i32,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("filter" = Filter, Query, style = "form", explode)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "filter",
+                "required": true,
+                "style": "form",
+                "explode": true,
+                "schema": {
+                    "$ref": "#/components/schemas/Filter"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_inherit_example_from_schema() {
+    #[derive(utoipa::ToSchema)]
+    #[schema(example = "a1b2c3")]
+    #[allow(unused)]
+    struct ItemId(String);
+
+    #[utoipa::path(
+        get,
+        path = "/items/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = ItemId, Path),
+            ("parent" = ItemId, Query, example = "d4e5f6")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_item() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_item), components(schemas(ItemId)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1items~1{id}/get/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "$ref": "#/components/schemas/ItemId"
+                }
+            },
+            {
+                "in": "query",
+                "name": "parent",
+                "required": true,
+                "example": "d4e5f6",
+                "schema": {
+                    "$ref": "#/components/schemas/ItemId"
+                }
+            }
+        ])
+    );
+    assert_json_eq!(
+        doc.pointer("/components/schemas/ItemId/example").unwrap(),
+        json!("a1b2c3")
+    );
+}
+
+#[test]
+fn derive_path_params_with_raw_string_example() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("filter" = String, Query, example = r#"{ "a": 1 }"#),
+            ("query" = String, Query, example = r#"{
+  "name": "item",
+  "tags": ["a\tb"]
+}"#)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+
+    assert_json_eq!(
+        operation.pointer("/parameters/0/example").unwrap(),
+        json!(r#"{ "a": 1 }"#)
+    );
+    assert_json_eq!(
+        operation.pointer("/parameters/1/example").unwrap(),
+        json!("{\n  \"name\": \"item\",\n  \"tags\": [\"a\\tb\"]\n}")
+    );
+}
+
+#[test]
+fn derive_path_params_with_cookie_security_attributes() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("session" = String, Cookie, x_samesite = "Strict", x_http_only),
+            ("theme" = Option<String>, Cookie, x_samesite = "Lax", x_http_only = false)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "cookie",
+                "name": "session",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                },
+                "x-samesite": "Strict",
+                "x-http-only": true
+            },
+            {
+                "in": "cookie",
+                "name": "theme",
+                "required": false,
+                "schema": {
+                    "type": ["string", "null"]
+                },
+                "x-samesite": "Lax",
+                "x-http-only": false
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_exclusive_bounds_as_numbers() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("price" = f64, Query, exclusive_minimum = 0, exclusive_maximum = 100.5),
+            ("count" = i32, Query, minimum = 1, exclusive_maximum = 10)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "price",
+                "required": true,
+                "schema": {
+                    "type": "number",
+                    "format": "double",
+                    "exclusiveMinimum": 0,
+                    "exclusiveMaximum": 100.5
+                }
+            },
+            {
+                "in": "query",
+                "name": "count",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 1,
+                    "exclusiveMaximum": 10
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_deprecated_from_deprecated_schema_type() {
+    #![allow(deprecated)]
+
+    #[derive(utoipa::ToSchema, serde::Deserialize)]
+    #[deprecated]
+    #[allow(unused)]
+    struct Filter {
+        name: String,
+    }
+
+    #[derive(utoipa::ToSchema, serde::Deserialize)]
+    #[allow(unused)]
+    struct Sort {
+        field: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("filter" = Option<Filter>, Query),
+            ("legacy_filter" = Filter, Query, deprecated = false),
+            ("sort" = Sort, Query)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_value! {parameters=>
+        "[0].deprecated" = r#"true"#, "Parameter deprecated by type"
+        "[1].deprecated" = r#"false"#, "Parameter explicitly not deprecated"
+        "[2].deprecated" = r#"null"#, "Parameter not deprecated"
+    }
+}
+
+#[test]
+fn derive_path_params_with_custom_parameter_in() {
+    #[utoipa::path(
+        post,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("payload" = String, in = "body", description = "Legacy body parameter"),
+            ("page" = i32, in = "query")
+        )
+    )]
+    #[allow(unused)]
+    async fn post_items() {}
+
+    let operation = serde_json::to_value(__path_post_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "body",
+                "name": "payload",
+                "description": "Legacy body parameter",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "in": "query",
+                "name": "page",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_description_template() {
+    #[utoipa::path(
+        get,
+        path = "/items/{item_id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("item_id" = i32, Path, description_template = "The {name} of the resource"),
+            ("sort" = String, Query, rename = "order", description_template = "{name} of {items}")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_value! {parameters=>
+        "[0].description" = r#""The item_id of the resource""#, "Parameter description"
+        "[1].description" = r#""order of {items}""#, "Renamed parameter description"
+    }
+}
+
+#[test]
+fn derive_path_params_with_cookie_arrays() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("prefs" = Vec<String>, Cookie),
+            ("tags" = Option<Vec<String>>, Cookie, explode),
+            ("theme" = String, Cookie)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "cookie",
+                "name": "prefs",
+                "required": true,
+                "style": "form",
+                "explode": false,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
+                }
+            },
+            {
+                "in": "cookie",
+                "name": "tags",
+                "required": false,
+                "style": "form",
+                "explode": true,
+                "schema": {
+                    "type": ["array", "null"],
+                    "items": {
+                        "type": "string"
+                    }
+                }
+            },
+            {
+                "in": "cookie",
+                "name": "theme",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_description_ref() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("page" = i32, Query, description = "Page number starting from 1"),
+            ("related_page" = Option<i32>, Query, description_ref = "page")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_value! {parameters=>
+        "[0].description" = r#""Page number starting from 1""#, "Parameter description"
+        "[1].description" = r#""Page number starting from 1""#, "Referenced description"
+    }
+}
+
+#[test]
+fn derive_path_params_option_nullability() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("page" = Option<i32>, Query),
+            ("size" = Option<i32>, Query, nullable = false)
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "page",
+                "required": false,
+                "schema": {
+                    "type": ["integer", "null"],
+                    "format": "int32"
+                }
+            },
+            {
+                "in": "query",
+                "name": "size",
+                "required": false,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_example_url() {
+    #[utoipa::path(
+        get,
+        path = "/items/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = i32, Path, example_url = "https://api.example.com/items/1")
+        )
+    )]
+    #[allow(unused)]
+    async fn get_item() {}
+
+    let operation = serde_json::to_value(__path_get_item::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "int32"
+                },
+                "x-example-url": "https://api.example.com/items/1"
+            }
+        ])
+    );
+}
+
+#[test]
+fn derive_path_params_with_content_encoding() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Filter {
+        tags: Vec<String>,
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("filter" = Filter, Query, content_type = "application/x-www-form-urlencoded",
+                encoding = {
+                    "tags": { style = Form, explode = false },
+                    "name": { content_type = "text/plain", allow_reserved }
+                }
+            ),
+            ("query", Query, content = { "application/x-www-form-urlencoded": Filter },
+                encoding = { "tags": { style = PipeDelimited } }
+            )
+        )
+    )]
+    #[allow(unused)]
+    async fn get_items() {}
+
+    let operation = serde_json::to_value(__path_get_items::operation()).unwrap();
+    let parameters = operation.pointer("/parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "filter",
+                "required": true,
+                "content": {
+                    "application/x-www-form-urlencoded": {
+                        "schema": {
+                            "$ref": "#/components/schemas/Filter"
+                        },
+                        "encoding": {
+                            "tags": {
+                                "style": "form",
+                                "explode": false
+                            },
+                            "name": {
+                                "contentType": "text/plain",
+                                "allowReserved": true
+                            }
+                        }
+                    }
+                }
+            },
+            {
+                "in": "query",
+                "name": "query",
+                "required": true,
+                "content": {
+                    "application/x-www-form-urlencoded": {
+                        "schema": {
+                            "$ref": "#/components/schemas/Filter"
+                        },
+                        "encoding": {
+                            "tags": {
+                                "style": "pipeDelimited"
+                            }
+                        }
+                    }
+                }
+            }
+        ])
+    );
+}
@@ -41,6 +41,32 @@ fn derive_map_type() {
     };
 }
 
+#[test]
+fn derive_struct_with_blank_doc_comments() {
+    let value = api_doc! {
+        ///
+        ///
+        struct Item {
+            ///
+            id: i32,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            },
+            "required": ["id"]
+        })
+    );
+}
+
 #[test]
 fn derive_map_ref() {
     #[derive(ToSchema)]
@@ -3625,6 +3651,33 @@ fn derive_parse_serde_field_attributes() {
     )
 }
 
+#[test]
+fn derive_struct_with_doc_hidden_field() {
+    let post = api_doc! {
+        struct Post {
+            id: String,
+            /// Internal revision of the post.
+            #[doc(hidden)]
+            revision: i64,
+        }
+    };
+
+    assert_json_eq!(
+        post,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "string"
+                }
+            },
+            "required": [
+                "id"
+            ],
+            "type": "object"
+        })
+    )
+}
+
 #[test]
 fn derive_parse_serde_simple_enum_attributes() {
     let value = api_doc! {
@@ -113,6 +113,8 @@
 //!   serialization and deserialization of `Arc<T>` and `Rc<T>` types. See more about [serde feature flags](https://serde.rs/feature-flags.html).
 //! * **`config`** Enables [`utoipa-config`](https://docs.rs/utoipa-config/) for the project which allows
 //!   defining global configuration options for `utoipa`.
+//! * **`localized_docs`** Add translations of `IntoParams` field doc comments from
+//!   `#[utoipa_doc(locale = "...", text = "...")]` attributes to `x-translations` extension of the parameters.
 //!
 //! ### Default Library Support
 //!
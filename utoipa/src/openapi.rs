@@ -182,10 +182,11 @@ impl OpenApi {
     /// Merge `other` [`OpenApi`] consuming it and resuming it's content.
     ///
     /// Merge function will take all `self` nonexistent _`servers`, `paths`, `schemas`, `responses`,
-    /// `security_schemes`, `security_requirements` and `tags`_ from _`other`_ [`OpenApi`].
+    /// `parameters`, `security_schemes`, `security_requirements` and `tags`_ from _`other`_
+    /// [`OpenApi`].
     ///
-    /// This function performs a shallow comparison for `paths`, `schemas`, `responses` and
-    /// `security schemes` which means that only _`name`_ and _`path`_ is used for comparison. When
+    /// This function performs a shallow comparison for `paths`, `schemas`, `responses`,
+    /// `parameters` and `security schemes` which means that only _`name`_ and _`path`_ is used for comparison. When
     /// match occurs the whole item will be ignored from merged results. Only items not
     /// found will be appended to `self`.
     ///
@@ -217,6 +218,13 @@ impl OpenApi {
                 .retain(|name, _| !components.responses.contains_key(name));
             components.responses.append(&mut other_components.responses);
 
+            other_components
+                .parameters
+                .retain(|name, _| !components.parameters.contains_key(name));
+            components
+                .parameters
+                .append(&mut other_components.parameters);
+
             other_components
                 .security_schemes
                 .retain(|name, _| !components.security_schemes.contains_key(name));
@@ -1,17 +1,21 @@
 //! Implements [OpenAPI Path Object][paths] types.
 //!
 //! [paths]: https://spec.openapis.org/oas/latest.html#paths-object
+use std::collections::BTreeMap;
+
 use crate::Path;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::{
     builder,
+    content::Content,
+    example::Example,
     extensions::Extensions,
     request_body::RequestBody,
     response::{Response, Responses},
     security::SecurityRequirement,
-    set_value, Deprecated, ExternalDocs, RefOr, Required, Schema, Server,
+    set_value, Deprecated, ExternalDocs, Ref, RefOr, Required, Schema, Server,
 };
 
 #[cfg(not(feature = "preserve_path_order"))]
@@ -484,9 +488,10 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub external_docs: Option<ExternalDocs>,
 
-        /// List of applicable parameters for this [`Operation`].
+        /// List of applicable parameters for this [`Operation`]. Parameters can be either inlined
+        /// or references to reusable parameters in [`Components`][crate::openapi::Components].
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub parameters: Option<Vec<Parameter>>,
+        pub parameters: Option<Vec<RefOr<Parameter>>>,
 
         /// Optional request body for this [`Operation`].
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -564,7 +569,7 @@ impl OperationBuilder {
     }
 
     /// Add or change parameters of the [`Operation`].
-    pub fn parameters<I: IntoIterator<Item = P>, P: Into<Parameter>>(
+    pub fn parameters<I: IntoIterator<Item = P>, P: Into<RefOr<Parameter>>>(
         mut self,
         parameters: Option<I>,
     ) -> Self {
@@ -583,8 +588,9 @@ impl OperationBuilder {
         self
     }
 
-    /// Append parameter to [`Operation`] parameters.
-    pub fn parameter<P: Into<Parameter>>(mut self, parameter: P) -> Self {
+    /// Append parameter to [`Operation`] parameters. Parameter can also be a [`Ref`] to reusable
+    /// parameter e.g. [`Ref::from_parameter_name`].
+    pub fn parameter<P: Into<RefOr<Parameter>>>(mut self, parameter: P) -> Self {
         match self.parameters {
             Some(ref mut parameters) => parameters.push(parameter.into()),
             None => {
@@ -705,6 +711,12 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub schema: Option<RefOr<Schema>>,
 
+        /// Map of [`Content`]s by content type e.g. `application/json` which can be used instead
+        /// of [`Parameter::schema`] for complex parameter values. The map must only contain one
+        /// entry.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub content: BTreeMap<String, Content>,
+
         /// Describes how [`Parameter`] is being serialized depending on [`Parameter::schema`] (type of a content).
         /// Default value is based on [`ParameterIn`].
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -731,11 +743,21 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub allow_reserved: Option<bool>,
 
+        /// Defines whether parameter can be sent with empty value e.g. _`?verbose`_. This is only
+        /// applicable with [`ParameterIn::Query`]. Default value is _`false`_.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allow_empty_value: Option<bool>,
+
         /// Example of [`Parameter`]'s potential value. This examples will override example
         /// within [`Parameter::schema`] if defined.
         #[serde(skip_serializing_if = "Option::is_none")]
         example: Option<Value>,
 
+        /// Map of named examples of [`Parameter`]'s potential value. [`Parameter::examples`] and
+        /// [`Parameter`]'s `example` are mutually exclusive.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub examples: BTreeMap<String, RefOr<Example>>,
+
         /// Optional extensions "x-something".
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -751,6 +773,57 @@ impl Parameter {
             ..Default::default()
         }
     }
+
+    /// Merge _`defaults`_ into `self` by filling only the fields that are unset in `self`. This
+    /// can be used to apply common defaults to many parameters while keeping the per parameter
+    /// overrides.
+    ///
+    /// Fields are considered unset as follows:
+    /// * `description`, `deprecated`, `style`, `explode`, `allow_reserved` and
+    ///   `allow_empty_value` when they are [`None`].
+    /// * `schema` and `content` when both of them are unset i.e. `schema` is [`None`] and
+    ///   `content` is empty since they are mutually exclusive.
+    /// * `example` and `examples` when both of them are unset i.e. `example` is [`None`] and
+    ///   `examples` is empty since they are mutually exclusive.
+    /// * `extensions` are merged key by key where existing extensions of `self` take precedence.
+    ///
+    /// `name`, `parameter_in` and `required` always have a value thus they are never changed.
+    pub fn merge(&mut self, defaults: &Parameter) {
+        if self.description.is_none() {
+            self.description.clone_from(&defaults.description);
+        }
+        if self.deprecated.is_none() {
+            self.deprecated.clone_from(&defaults.deprecated);
+        }
+        if self.schema.is_none() && self.content.is_empty() {
+            self.schema.clone_from(&defaults.schema);
+            self.content.clone_from(&defaults.content);
+        }
+        if self.style.is_none() {
+            self.style.clone_from(&defaults.style);
+        }
+        if self.explode.is_none() {
+            self.explode = defaults.explode;
+        }
+        if self.allow_reserved.is_none() {
+            self.allow_reserved = defaults.allow_reserved;
+        }
+        if self.allow_empty_value.is_none() {
+            self.allow_empty_value = defaults.allow_empty_value;
+        }
+        if self.example.is_none() && self.examples.is_empty() {
+            self.example.clone_from(&defaults.example);
+            self.examples.clone_from(&defaults.examples);
+        }
+        if let Some(default_extensions) = &defaults.extensions {
+            let extensions = self.extensions.get_or_insert_with(Extensions::default);
+            for (name, value) in default_extensions.iter() {
+                extensions
+                    .entry(name.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+    }
 }
 
 impl ParameterBuilder {
@@ -791,6 +864,14 @@ impl ParameterBuilder {
         set_value!(self schema component.map(|component| component.into()))
     }
 
+    /// Add [`Content`] by content type e.g `application/json` to [`Parameter`]. This can be used
+    /// instead of [`ParameterBuilder::schema`] for complex parameter values.
+    pub fn content<S: Into<String>>(mut self, content_type: S, content: Content) -> Self {
+        self.content.insert(content_type.into(), content);
+
+        self
+    }
+
     /// Add or change serialization style of [`Parameter`].
     pub fn style(mut self, style: Option<ParameterStyle>) -> Self {
         set_value!(self style style)
@@ -806,18 +887,60 @@ impl ParameterBuilder {
         set_value!(self allow_reserved allow_reserved)
     }
 
+    /// Add or change whether [`Parameter`] can be sent with empty value.
+    pub fn allow_empty_value(mut self, allow_empty_value: Option<bool>) -> Self {
+        set_value!(self allow_empty_value allow_empty_value)
+    }
+
     /// Add or change example of [`Parameter`]'s potential value.
     pub fn example(mut self, example: Option<Value>) -> Self {
         set_value!(self example example)
     }
 
+    /// Add iterator of _`(N, V)`_ where `N` is name of the example and `V` is [`Example`][example]
+    /// to [`Parameter`]'s examples.
+    ///
+    /// [example]: ../example/Example.html
+    pub fn examples_from_iter<
+        E: IntoIterator<Item = (N, V)>,
+        N: Into<String>,
+        V: Into<RefOr<Example>>,
+    >(
+        mut self,
+        examples: E,
+    ) -> Self {
+        self.examples.extend(
+            examples
+                .into_iter()
+                .map(|(name, example)| (name.into(), example.into())),
+        );
+
+        self
+    }
+
     /// Add openapi extensions (x-something) to the [`Parameter`].
     pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
         set_value!(self extensions extensions)
     }
 }
 
+impl From<ParameterBuilder> for RefOr<Parameter> {
+    fn from(builder: ParameterBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
+impl From<Ref> for RefOr<Parameter> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
+
 /// In definition of [`Parameter`].
+///
+/// The enum is non-exhaustive since OpenAPI allows non-standard locations with
+/// [`ParameterIn::Other`].
+#[non_exhaustive]
 #[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "lowercase")]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -830,6 +953,10 @@ pub enum ParameterIn {
     Header,
     /// Declares that parameter is used as cookie value.
     Cookie,
+    /// Declares non-standard location of the parameter e.g. _`body`_ of legacy API definitions.
+    /// The location is serialized as is.
+    #[serde(untagged)]
+    Other(String),
 }
 
 impl Default for ParameterIn {
@@ -838,6 +965,33 @@ impl Default for ParameterIn {
     }
 }
 
+impl ParameterIn {
+    /// Construct [`ParameterIn::Other`] with non-standard parameter location.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::path::ParameterIn;
+    /// let parameter_in = ParameterIn::custom("body");
+    /// ```
+    pub fn custom<S: Into<String>>(location: S) -> Self {
+        Self::Other(location.into())
+    }
+
+    /// Get default [`ParameterStyle`] of the [`ParameterIn`] which applies when
+    /// [`Parameter::style`] is not defined.
+    ///
+    /// * [`ParameterStyle::Form`] for [`ParameterIn::Query`] and [`ParameterIn::Cookie`].
+    /// * [`ParameterStyle::Simple`] for [`ParameterIn::Path`], [`ParameterIn::Header`] and
+    ///   [`ParameterIn::Other`].
+    pub fn default_style(&self) -> ParameterStyle {
+        match self {
+            Self::Query | Self::Cookie => ParameterStyle::Form,
+            Self::Path | Self::Header | Self::Other(_) => ParameterStyle::Simple,
+        }
+    }
+}
+
 /// Defines how [`Parameter`] should be serialized.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -871,8 +1025,14 @@ pub enum ParameterStyle {
 
 #[cfg(test)]
 mod tests {
-    use super::{HttpMethod, Operation, OperationBuilder};
-    use crate::openapi::{security::SecurityRequirement, server::Server, PathItem, PathsBuilder};
+    use super::{
+        HttpMethod, Operation, OperationBuilder, Parameter, ParameterBuilder, ParameterIn,
+        ParameterStyle,
+    };
+    use crate::openapi::{
+        extensions::ExtensionsBuilder, security::SecurityRequirement, server::Server, Deprecated,
+        ObjectBuilder, PathItem, PathsBuilder, Ref, Required, Type,
+    };
 
     #[test]
     fn test_path_order() {
@@ -1012,4 +1172,129 @@ mod tests {
 
         assert!(operation.servers.is_some());
     }
+
+    #[test]
+    fn operation_builder_parameter_ref() {
+        let operation = OperationBuilder::new()
+            .parameter(Ref::from_parameter_name("Page"))
+            .parameter(Parameter::new("id"))
+            .build();
+
+        let value = serde_json::to_value(&operation).unwrap();
+        assert_eq!(
+            value["parameters"],
+            serde_json::json!([
+                { "$ref": "#/components/parameters/Page" },
+                { "name": "id", "in": "path", "required": true }
+            ])
+        );
+
+        let deserialized: Operation = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&deserialized).unwrap(), value);
+    }
+
+    #[test]
+    fn parameter_in_default_style() {
+        assert!(ParameterIn::Query.default_style() == ParameterStyle::Form);
+        assert!(ParameterIn::Cookie.default_style() == ParameterStyle::Form);
+        assert!(ParameterIn::Path.default_style() == ParameterStyle::Simple);
+        assert!(ParameterIn::Header.default_style() == ParameterStyle::Simple);
+        assert!(ParameterIn::custom("body").default_style() == ParameterStyle::Simple);
+    }
+
+    #[test]
+    fn parameter_in_custom_location() {
+        let parameter = ParameterBuilder::new()
+            .name("payload")
+            .parameter_in(ParameterIn::custom("body"))
+            .build();
+
+        let value = serde_json::to_value(&parameter).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({ "name": "payload", "in": "body", "required": false })
+        );
+
+        let parameter: Parameter = serde_json::from_value(value).unwrap();
+        assert!(parameter.parameter_in == ParameterIn::Other(String::from("body")));
+        let parameter: Parameter = serde_json::from_value(
+            serde_json::json!({ "name": "id", "in": "query", "required": true }),
+        )
+        .unwrap();
+        assert!(parameter.parameter_in == ParameterIn::Query);
+    }
+
+    #[test]
+    fn parameter_with_allow_empty_value() {
+        let parameter = ParameterBuilder::new()
+            .name("verbose")
+            .parameter_in(ParameterIn::Query)
+            .required(Required::False)
+            .allow_empty_value(Some(true))
+            .schema(Some(ObjectBuilder::new().schema_type(Type::Boolean)))
+            .build();
+
+        let value = serde_json::to_value(parameter).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "name": "verbose",
+                "in": "query",
+                "required": false,
+                "allowEmptyValue": true,
+                "schema": {
+                    "type": "boolean"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn parameter_merge_fills_unset_fields() {
+        let defaults = ParameterBuilder::new()
+            .name("default")
+            .description(Some("Default description"))
+            .deprecated(Some(Deprecated::False))
+            .schema(Some(ObjectBuilder::new().schema_type(Type::String)))
+            .explode(Some(true))
+            .example(Some(serde_json::json!("default")))
+            .extensions(Some(
+                ExtensionsBuilder::new()
+                    .add("x-gateway", "public")
+                    .add("x-team", "core")
+                    .build(),
+            ))
+            .build();
+
+        let mut parameter = ParameterBuilder::new()
+            .name("id")
+            .description(Some("Id of the item"))
+            .schema(Some(ObjectBuilder::new().schema_type(Type::Integer)))
+            .extensions(Some(
+                ExtensionsBuilder::new()
+                    .add("x-gateway", "internal")
+                    .build(),
+            ))
+            .build();
+        parameter.merge(&defaults);
+
+        let value = serde_json::to_value(parameter).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "name": "id",
+                "in": "path",
+                "required": false,
+                "description": "Id of the item",
+                "deprecated": false,
+                "schema": {
+                    "type": "integer"
+                },
+                "explode": true,
+                "example": "default",
+                "x-gateway": "internal",
+                "x-team": "core"
+            })
+        );
+    }
 }
@@ -9,7 +9,9 @@ use serde_json::Value;
 
 use super::extensions::Extensions;
 use super::RefOr;
-use super::{builder, security::SecurityScheme, set_value, xml::Xml, Deprecated, Response};
+use super::{
+    builder, path::Parameter, security::SecurityScheme, set_value, xml::Xml, Deprecated, Response,
+};
 use crate::{ToResponse, ToSchema};
 
 macro_rules! component_from_builder {
@@ -73,6 +75,14 @@ builder! {
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub responses: BTreeMap<String, RefOr<Response>>,
 
+        /// Map of reusable parameter name, to [OpenAPI Parameter Object][parameter]s or [OpenAPI
+        /// Reference][reference]s to [OpenAPI Parameter Object][parameter]s.
+        ///
+        /// [parameter]: https://spec.openapis.org/oas/latest.html#parameter-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub parameters: BTreeMap<String, RefOr<Parameter>>,
+
         /// Map of reusable [OpenAPI Security Scheme Object][security_scheme]s.
         ///
         /// [security_scheme]: https://spec.openapis.org/oas/latest.html#security-scheme-object
@@ -242,6 +252,41 @@ impl ComponentsBuilder {
         self
     }
 
+    /// Add [`Parameter`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable parameter and `parameter` which is the
+    /// reusable parameter itself. Operations can reference the parameter with
+    /// [`Ref::from_parameter_name`].
+    pub fn parameter<S: Into<String>, P: Into<RefOr<Parameter>>>(
+        mut self,
+        name: S,
+        parameter: P,
+    ) -> Self {
+        self.parameters.insert(name.into(), parameter.into());
+        self
+    }
+
+    /// Add multiple [`Parameter`]s to [`Components`] from iterator.
+    ///
+    /// Like the [`ComponentsBuilder::responses_from_iter`] this allows adding multiple parameters
+    /// by any iterator what returns tuples of (name, parameter) values.
+    pub fn parameters_from_iter<
+        I: IntoIterator<Item = (S, P)>,
+        S: Into<String>,
+        P: Into<RefOr<Parameter>>,
+    >(
+        mut self,
+        parameters: I,
+    ) -> Self {
+        self.parameters.extend(
+            parameters
+                .into_iter()
+                .map(|(name, parameter)| (name.into(), parameter.into())),
+        );
+
+        self
+    }
+
     /// Add [`SecurityScheme`] to [`Components`].
     ///
     /// Accepts two arguments where first is the name of the [`SecurityScheme`]. This is later when
@@ -304,6 +349,20 @@ impl Default for Schema {
     }
 }
 
+impl Schema {
+    /// Check whether the schema is marked deprecated. Only [`Schema::Object`] and
+    /// [`Schema::Array`] can be deprecated, composite schemas are never considered deprecated.
+    pub fn is_deprecated(&self) -> bool {
+        let deprecated = match self {
+            Self::Object(object) => object.deprecated.as_ref(),
+            Self::Array(array) => array.deprecated.as_ref(),
+            _ => None,
+        };
+
+        matches!(deprecated, Some(Deprecated::True))
+    }
+}
+
 /// OpenAPI [Discriminator][discriminator] object which can be optionally used together with
 /// [`OneOf`] composite object.
 ///
@@ -947,6 +1006,10 @@ builder! {
         #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
         pub enum_values: Option<Vec<Value>>,
 
+        /// Single allowed value of the [`Object`] e.g. fixed value of a header.
+        #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+        pub const_value: Option<Value>,
+
         /// Vector of required field names.
         #[serde(skip_serializing_if = "Vec::is_empty", default = "Vec::new")]
         pub required: Vec<String>,
@@ -1188,6 +1251,11 @@ impl ObjectBuilder {
             enum_values.map(|values| values.into_iter().map(|enum_value| enum_value.into()).collect()))
     }
 
+    /// Add or change the single allowed value of the [`Object`].
+    pub fn const_value<V: Into<Value>>(mut self, const_value: Option<V>) -> Self {
+        set_value!(self const_value const_value.map(|const_value| const_value.into()))
+    }
+
     /// Add or change example shown in UI of the value for richer documentation.
     ///
     /// **Deprecated since 3.0.x. Prefer [`Object::examples`] instead**
@@ -1422,6 +1490,12 @@ impl Ref {
         Self::new(format!("#/components/responses/{}", response_name.into()))
     }
 
+    /// Construct a new [`Ref`] from provided parameter name. This will create a [`Ref`] that
+    /// references the reusable parameter.
+    pub fn from_parameter_name<I: Into<String>>(parameter_name: I) -> Self {
+        Self::new(format!("#/components/parameters/{}", parameter_name.into()))
+    }
+
     to_array_builder!();
 }
 
@@ -2351,6 +2425,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_schema_is_deprecated() {
+        let deprecated: Schema = ObjectBuilder::new()
+            .deprecated(Some(Deprecated::True))
+            .into();
+        let not_deprecated: Schema = ObjectBuilder::new()
+            .deprecated(Some(Deprecated::False))
+            .into();
+
+        assert!(deprecated.is_deprecated());
+        assert!(!not_deprecated.is_deprecated());
+        assert!(!Schema::Object(Object::new()).is_deprecated());
+        assert!(!Schema::OneOf(OneOf::new()).is_deprecated());
+    }
+
+    #[test]
+    fn test_object_with_const_value() {
+        let json_value = ObjectBuilder::new()
+            .schema_type(Type::String)
+            .const_value(Some("2"))
+            .build();
+        assert_json_eq!(
+            json_value,
+            json!({
+                "type": "string",
+                "const": "2"
+            })
+        );
+    }
+
     #[test]
     fn derive_object_with_examples() {
         let expected = r#"{"type":"object","examples":[{"age":20,"name":"bob the cat"}]}"#;